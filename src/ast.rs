@@ -1,3 +1,4 @@
+use crate::location::Location;
 use crate::types::{Type, VariableValue};
 
 #[derive(Debug, Clone)]
@@ -8,6 +9,7 @@ pub struct Program {
 #[derive(Debug, Clone)]
 pub struct ImportStatement {
     pub path: String,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
@@ -19,30 +21,84 @@ pub enum Statement {
     If(IfStatement),
     WhileLoop(WhileLoop),
     ForLoop(ForLoop),
-    Break,
-    Continue,
+    ForEachLoop(ForEachLoop),
+    Break(Location),
+    Continue(Location),
     #[allow(dead_code)]
     FunctionDefinition(FunctionDefinition),
-    #[allow(dead_code)]
-    Return(Option<Expression>),
+    Return(ReturnStatement),
     StructDefinition(StructDefinition),
+    /// An expression evaluated only for its effects, such as a procedure call
+    Expression(Expression),
+}
+
+impl Statement {
+    /// Where the statement is written
+    pub fn location(&self) -> &Location {
+        match self {
+            Statement::Import(import) => &import.location,
+            Statement::VariableDeclaration(decl) => &decl.location,
+            Statement::Assignment(assign) => &assign.location,
+            Statement::Output(output) => &output.location,
+            Statement::If(if_stmt) => &if_stmt.location,
+            Statement::WhileLoop(while_loop) => &while_loop.location,
+            Statement::ForLoop(for_loop) => &for_loop.location,
+            Statement::ForEachLoop(for_each) => &for_each.location,
+            Statement::Break(location) | Statement::Continue(location) => location,
+            Statement::FunctionDefinition(func) => &func.location,
+            Statement::Return(ret) => &ret.location,
+            Statement::StructDefinition(def) => &def.location,
+            Statement::Expression(expr) => &expr.location,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct VariableDeclaration {
     pub name: String,
-    pub var_type: Type,
+    /// `None` for `değişken`
+    pub suffix: Option<Suffix>,
+    /// `None` when the type is inferred from the initializer
+    pub var_type: Option<Type>,
+    /// Starting value; the type's default value when absent
+    pub initializer: Option<Expression>,
+    pub location: Location,
+}
+
+/// Possessive suffix of a declared name as written, e.g. `'yı`
+#[derive(Debug, Clone)]
+pub struct Suffix {
+    pub text: String,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
 pub struct Assignment {
     pub name: String,
+    /// Path from the variable to the part being assigned, e.g. `.adres.şehir`
+    /// or `.öğrenciler[2].not`. Empty when the whole variable is assigned.
+    pub accessors: Vec<Accessor>,
     pub expression: Expression,
+    pub location: Location,
+}
+
+#[derive(Debug, Clone)]
+pub enum Accessor {
+    Field(String),
+    Index(Expression),
 }
 
 #[derive(Debug, Clone)]
 pub struct OutputStatement {
     pub expression: Expression,
+    #[allow(dead_code)]
+    pub location: Location,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReturnStatement {
+    pub value: Option<Expression>,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
@@ -53,11 +109,11 @@ pub struct ControlBlock {
 #[derive(Debug, Clone)]
 pub struct Condition {
     pub expression: Box<Expression>,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
 pub struct LoopVariable {
-    #[allow(dead_code)]
     pub name: String,
     #[allow(dead_code)]
     pub is_auto_generated: bool,
@@ -68,26 +124,39 @@ pub struct IfStatement {
     pub condition: Condition,
     pub then_block: ControlBlock,
     pub else_block: Option<ControlBlock>,
+    #[allow(dead_code)]
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
 pub struct WhileLoop {
     pub condition: Condition,
     pub body: ControlBlock,
+    pub location: Location,
 }
 
+/// Counted loop: `için i in başlangıç dan bitiş (adım n) ise ... son`.
+/// Both bounds are inclusive.
 #[derive(Debug, Clone)]
 pub struct ForLoop {
-    #[allow(dead_code)]
     pub loop_variable: LoopVariable,
-    #[allow(dead_code)]
     pub range_start: Box<Expression>,
-    #[allow(dead_code)]
     pub range_end: Box<Expression>,
-    #[allow(dead_code)]
     pub step: Option<Box<Expression>>,
-    #[allow(dead_code)]
     pub body: ControlBlock,
+    pub location: Location,
+}
+
+/// Loop over the elements of an array or the characters of a string:
+/// `için i, sayı in sayılar ise ... son`
+#[derive(Debug, Clone)]
+pub struct ForEachLoop {
+    /// Optional variable holding the position of the current element
+    pub index_variable: Option<String>,
+    pub loop_variable: LoopVariable,
+    pub iterable: Expression,
+    pub body: ControlBlock,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
@@ -97,6 +166,7 @@ pub struct FunctionDefinition {
     pub parameters: Vec<Parameter>,
     pub return_type: Option<Type>,
     pub body: Vec<Statement>,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
@@ -104,6 +174,7 @@ pub struct FunctionDefinition {
 pub struct Parameter {
     pub name: String,
     pub param_type: Type,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
@@ -114,17 +185,16 @@ pub struct ArrayLiteral {
 }
 
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct StructLiteral {
     pub struct_name: String,
     pub fields: Vec<FieldAssignment>,
 }
 
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct FieldAssignment {
     pub name: String,
     pub value: Expression,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
@@ -134,7 +204,6 @@ pub struct ArrayAccess {
 }
 
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct StructAccess {
     pub struct_expr: Box<Expression>,
     pub field_name: String,
@@ -143,44 +212,84 @@ pub struct StructAccess {
 #[derive(Debug, Clone)]
 pub struct StructDefinition {
     pub name: String,
-    #[allow(dead_code)]
     pub fields: Vec<FieldDefinition>,
+    pub location: Location,
 }
 
 #[derive(Debug, Clone)]
-#[allow(dead_code)]
 pub struct FieldDefinition {
     pub name: String,
     pub field_type: Type,
+    pub location: Location,
 }
 
+/// An expression and the source it was parsed from
 #[derive(Debug, Clone)]
-pub enum Expression {
+pub struct Expression {
+    pub kind: ExpressionKind,
+    pub location: Location,
+}
+
+impl Expression {
+    pub fn new(kind: ExpressionKind, location: Location) -> Self {
+        Self { kind, location }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ExpressionKind {
     VariableRef(String),
     Literal(VariableValue),
     BinaryOp(Box<Expression>, BinaryOperator, Box<Expression>),
+    UnaryOp(UnaryOperator, Box<Expression>),
     #[allow(dead_code)]
     FunctionCall(FunctionCall),
     ArrayLiteral(ArrayLiteral),
-    #[allow(dead_code)]
     StructLiteral(StructLiteral),
     ArrayAccess(ArrayAccess),
-    #[allow(dead_code)]
     StructAccess(StructAccess),
 }
 
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct FunctionCall {
-    pub name: String,
+    /// The called expression. Calls parse after any expression, but only
+    /// function names can be called.
+    pub callee: Box<Expression>,
     pub arguments: Vec<Expression>,
+    pub location: Location,
+}
+
+impl FunctionCall {
+    /// Name of the called function, `None` when the callee is not a name
+    pub fn name(&self) -> Option<&str> {
+        match &self.callee.kind {
+            ExpressionKind::VariableRef(name) => Some(name),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum BinaryOperator {
     Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Power,
+    Equal,
+    NotEqual,
     GreaterThan,
     GreaterThanOrEqual,
     LessThan,
     LessThanOrEqual,
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone)]
+pub enum UnaryOperator {
+    Negate,
+    Not,
 }
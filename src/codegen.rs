@@ -1,37 +1,90 @@
 use crate::ast::*;
+use crate::error_reporting::{OtagError, Result, RuntimeErrorKind};
+use crate::location::Location;
 use crate::symbol_table::SymbolTable;
 use crate::types::*;
-use std::collections::HashMap;
+
+/// How execution continues after a statement. Loops consume `Break` and
+/// `Continue`; function calls consume `Return`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ControlFlow {
+    Normal,
+    Break,
+    Continue,
+    Return(Option<VariableValue>),
+}
+
+/// Deepest chain of nested function calls before execution is stopped
+const MAX_CALL_DEPTH: usize = 10_000;
+
+/// An Otağ call takes several Rust frames, up to tens of kilobytes in debug
+/// builds. A call that finds less than `STACK_RED_ZONE` of stack left runs its
+/// body on a new `STACK_SEGMENT` allocated on the heap, so deep recursion does
+/// not overflow the caller's thread.
+const STACK_RED_ZONE: usize = 1024 * 1024;
+const STACK_SEGMENT: usize = 16 * 1024 * 1024;
 
 pub struct Interpreter {
+    /// Functions, structs and the values of all variables in scope
     pub symbol_table: SymbolTable,
-    pub variables: HashMap<String, VariableValue>,
+    /// When set, `söyle` output is collected here instead of printed to stdout
+    captured_output: Option<Vec<String>>,
 }
 
 impl Interpreter {
     pub fn new() -> Self {
         Interpreter {
             symbol_table: SymbolTable::new(),
-            variables: HashMap::new(),
+            captured_output: None,
         }
     }
 
-    pub fn execute_program(&mut self, program: &Program) -> Result<(), String> {
+    /// Collect program output in memory instead of printing it
+    #[allow(dead_code)]
+    pub fn capture_output(&mut self) {
+        self.captured_output = Some(Vec::new());
+    }
+
+    /// Take the lines collected since output capturing was enabled
+    #[allow(dead_code)]
+    pub fn take_output(&mut self) -> Vec<String> {
+        self.captured_output
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    pub fn execute_program(&mut self, program: &Program) -> Result<()> {
+        // Functions and structs can be used before their definition, so
+        // register all top-level ones first
         for statement in &program.statements {
-            let _ = self.execute_statement(statement)?;
+            match statement {
+                Statement::FunctionDefinition(func) => self.define_function(func)?,
+                Statement::StructDefinition(def) => self.define_struct(def)?,
+                _ => {}
+            }
+        }
+        for statement in &program.statements {
+            // A top-level `return` ends the program
+            if let ControlFlow::Return(_) = self.execute_statement(statement)? {
+                break;
+            }
         }
         Ok(())
     }
 
-    fn execute_statement(
-        &mut self,
-        statement: &Statement,
-    ) -> Result<Option<VariableValue>, String> {
+    fn execute_statement(&mut self, statement: &Statement) -> Result<ControlFlow> {
+        // Errors without a narrower span point at the whole statement
+        self.execute_statement_kind(statement)
+            .map_err(|error| error.or_at(statement.location()))
+    }
+
+    fn execute_statement_kind(&mut self, statement: &Statement) -> Result<ControlFlow> {
         match statement {
             Statement::Import(_) => {
                 // Import statements are handled at the parsing/loading phase
                 // and don't need runtime execution
-                Ok(None)
+                Ok(ControlFlow::Normal)
             }
             Statement::VariableDeclaration(decl) => self.execute_variable_declaration(decl),
             Statement::Assignment(assign) => self.execute_assignment(assign),
@@ -39,154 +92,391 @@ impl Interpreter {
             Statement::If(if_stmt) => self.execute_if_statement(if_stmt),
             Statement::WhileLoop(while_loop) => self.execute_while_loop(while_loop),
             Statement::ForLoop(for_loop) => self.execute_for_loop(for_loop),
-            Statement::Break => self.execute_break(),
-            Statement::Continue => self.execute_continue(),
+            Statement::ForEachLoop(for_each) => self.execute_for_each_loop(for_each),
+            Statement::Break(_) => self.execute_break(),
+            Statement::Continue(_) => self.execute_continue(),
             Statement::FunctionDefinition(func) => {
-                self.symbol_table.insert_function(func.clone())?;
-                Ok(None)
+                self.define_function(func)?;
+                Ok(ControlFlow::Normal)
             }
-            Statement::Return(expr) => {
-                if let Some(e) = expr {
+            Statement::Return(ret) => {
+                if let Some(e) = &ret.value {
                     let val = self.evaluate_expression(e)?;
-                    Ok(Some(val))
+                    Ok(ControlFlow::Return(Some(val)))
                 } else {
-                    Ok(None)
+                    Ok(ControlFlow::Return(None))
                 }
             }
             Statement::StructDefinition(def) => {
-                self.symbol_table.insert_struct(def.clone())?;
-                Ok(None)
+                self.define_struct(def)?;
+                Ok(ControlFlow::Normal)
+            }
+            Statement::Expression(Expression {
+                kind: ExpressionKind::FunctionCall(call),
+                ..
+            }) => {
+                // The result of a call used as a statement is discarded
+                self.call_function(call)?;
+                Ok(ControlFlow::Normal)
+            }
+            Statement::Expression(expr) => {
+                self.evaluate_expression(expr)?;
+                Ok(ControlFlow::Normal)
             }
         }
     }
 
-    fn execute_variable_declaration(
-        &mut self,
-        decl: &VariableDeclaration,
-    ) -> Result<Option<VariableValue>, String> {
+    /// Register a function unless it was already hoisted. Conflicting
+    /// definitions are rejected by the semantic analyzer.
+    fn define_function(&mut self, func: &FunctionDefinition) -> Result<()> {
+        if self.symbol_table.lookup_function(&func.name).is_some() {
+            return Ok(());
+        }
         self.symbol_table
-            .insert(decl.name.clone(), decl.var_type.clone());
-        // Initialize with default values
-        let default_value = match decl.var_type {
-            Type::Tamsayi => VariableValue::Int(0),
-            Type::Metin => VariableValue::String(String::new()),
-            Type::Ondalikli => VariableValue::Float(0.0),
-            Type::Mantiksal => VariableValue::Bool(false),
-            _ => {
-                return Err(format!(
-                    "Unsupported type for variable declaration: {:?}",
-                    decl.var_type
-                ))
-            }
+            .insert_function(func.clone())
+            .map_err(|message| {
+                OtagError::runtime(
+                    RuntimeErrorKind::DuplicateDefinition,
+                    message,
+                    func.location.clone(),
+                )
+            })
+    }
+
+    fn define_struct(&mut self, def: &StructDefinition) -> Result<()> {
+        if self.symbol_table.lookup_struct(&def.name).is_some() {
+            return Ok(());
+        }
+        self.symbol_table
+            .insert_struct(def.clone())
+            .map_err(|message| {
+                OtagError::runtime(
+                    RuntimeErrorKind::DuplicateDefinition,
+                    message,
+                    def.location.clone(),
+                )
+            })
+    }
+
+    fn execute_variable_declaration(&mut self, decl: &VariableDeclaration) -> Result<ControlFlow> {
+        // The initializer is evaluated before the new variable is in scope
+        let value = match (&decl.initializer, &decl.var_type) {
+            (Some(initializer), _) => self.evaluate_expression(initializer)?,
+            (None, Some(var_type)) => self.default_value(var_type)?,
+            (None, None) => unreachable!("inferred declarations always have an initializer"),
         };
-        self.variables.insert(decl.name.clone(), default_value);
-        Ok(None)
+        let var_type = decl.var_type.clone().unwrap_or_else(|| value.value_type());
+        self.symbol_table
+            .insert_with_value(decl.name.clone(), var_type, value);
+        Ok(ControlFlow::Normal)
     }
 
-    fn execute_assignment(&mut self, assign: &Assignment) -> Result<Option<VariableValue>, String> {
+    /// Value a newly declared variable starts with
+    fn default_value(&self, var_type: &Type) -> Result<VariableValue> {
+        match var_type {
+            Type::Tamsayi => Ok(VariableValue::Int(0)),
+            Type::Metin => Ok(VariableValue::String(String::new())),
+            Type::Ondalikli => Ok(VariableValue::Float(0.0)),
+            Type::Mantiksal => Ok(VariableValue::Bool(false)),
+            Type::Array(_) => Ok(VariableValue::Array(Vec::new())),
+            Type::Struct(name) => {
+                let def = self.symbol_table.lookup_struct(name).ok_or_else(|| {
+                    runtime_error(
+                        RuntimeErrorKind::UndefinedStruct,
+                        format!("Tanımlanmamış yapı: {}", name),
+                    )
+                })?;
+                let fields = def
+                    .fields
+                    .iter()
+                    .map(|field| Ok((field.name.clone(), self.default_value(&field.field_type)?)))
+                    .collect::<Result<_>>()?;
+                Ok(VariableValue::Struct {
+                    name: name.clone(),
+                    fields,
+                })
+            }
+            Type::Unknown => unreachable!("programs with type errors are never run"),
+        }
+    }
+
+    fn execute_assignment(&mut self, assign: &Assignment) -> Result<ControlFlow> {
         let value = self.evaluate_expression(&assign.expression)?;
-        self.variables.insert(assign.name.clone(), value);
-        Ok(None)
+        // Indices are evaluated before the target is borrowed for the update
+        let mut indices = Vec::new();
+        for accessor in &assign.accessors {
+            if let Accessor::Index(index) = accessor {
+                indices.push(self.evaluate_expression(index)?);
+            }
+        }
+        let mut indices = indices.into_iter();
+        let mut target = self.symbol_table.value_mut(&assign.name).ok_or_else(|| {
+            OtagError::runtime(
+                RuntimeErrorKind::UndefinedVariable,
+                format!("Tanımlanmamış değişken: {}", assign.name),
+                assign.location.clone(),
+            )
+        })?;
+        for accessor in &assign.accessors {
+            target = match accessor {
+                Accessor::Field(field) => target.field_mut(field).ok_or_else(|| {
+                    OtagError::runtime(
+                        RuntimeErrorKind::UnknownField,
+                        format!("'{}' adlı bir alan yok", field),
+                        assign.location.clone(),
+                    )
+                })?,
+                Accessor::Index(_) => {
+                    let index = indices.next().unwrap();
+                    let VariableValue::Array(elements) = target else {
+                        return Err(OtagError::runtime(
+                            RuntimeErrorKind::TypeMismatch,
+                            format!("Yalnızca diziler indekslenebilir, bulunan: {}", target),
+                            assign.location.clone(),
+                        ));
+                    };
+                    let position = element_position(&index, elements.len())
+                        .map_err(|error| error.or_at(&assign.location))?;
+                    &mut elements[position]
+                }
+            };
+        }
+        *target = value;
+        Ok(ControlFlow::Normal)
     }
 
-    fn execute_output_statement(
-        &mut self,
-        output: &OutputStatement,
-    ) -> Result<Option<VariableValue>, String> {
+    fn execute_output_statement(&mut self, output: &OutputStatement) -> Result<ControlFlow> {
         let value = self.evaluate_expression(&output.expression)?;
-        match value {
-            VariableValue::Int(i) => println!("{}", i),
-            VariableValue::String(s) => println!("{}", s),
-            VariableValue::Float(f) => println!("{}", f),
-            VariableValue::Bool(b) => println!("{}", if b { "doğru" } else { "yanlış" }),
-            VariableValue::Array(arr) => println!("{:?}", arr),
-        }
-        Ok(None)
-    }
-
-    fn evaluate_expression(&mut self, expr: &Expression) -> Result<VariableValue, String> {
-        match expr {
-            Expression::VariableRef(name) => self
-                .variables
-                .get(name)
-                .cloned()
-                .ok_or_else(|| format!("Undefined variable: {}", name)),
-            Expression::Literal(value) => Ok(value.clone()),
-            Expression::BinaryOp(left, op, right) => {
+        let line = value.to_string();
+        match &mut self.captured_output {
+            Some(lines) => lines.push(line),
+            None => println!("{}", line),
+        }
+        Ok(ControlFlow::Normal)
+    }
+
+    fn evaluate_expression(&mut self, expr: &Expression) -> Result<VariableValue> {
+        match &expr.kind {
+            ExpressionKind::VariableRef(name) => self
+                .symbol_table
+                .lookup(name)
+                .and_then(|variable| variable.value.clone())
+                .ok_or_else(|| {
+                    OtagError::runtime(
+                        RuntimeErrorKind::UndefinedVariable,
+                        format!("Tanımlanmamış değişken: {}", name),
+                        expr.location.clone(),
+                    )
+                }),
+            ExpressionKind::Literal(value) => Ok(value.clone()),
+            ExpressionKind::BinaryOp(
+                left,
+                op @ (BinaryOperator::And | BinaryOperator::Or),
+                right,
+            ) => self.evaluate_logical_op(left, op, right),
+            ExpressionKind::BinaryOp(left, op, right) => {
                 let left_val = self.evaluate_expression(left)?;
                 let right_val = self.evaluate_expression(right)?;
                 self.evaluate_binary_op(left_val, right_val, op)
+                    .map_err(|error| error.or_at(&expr.location))
             }
-            Expression::FunctionCall(call) => {
-                let func = self
-                    .symbol_table
-                    .lookup_function(&call.name)
-                    .ok_or_else(|| format!("Undefined function: {}", call.name))?
-                    .clone();
-                if call.arguments.len() != func.parameters.len() {
-                    return Err(format!(
-                        "Function '{}' expects {} arguments, got {}",
-                        call.name,
-                        func.parameters.len(),
-                        call.arguments.len()
-                    ));
-                }
-                let mut arg_values = Vec::new();
-                for arg in &call.arguments {
-                    arg_values.push(self.evaluate_expression(arg)?);
-                }
-                // Push new scope
-                self.symbol_table.push_scope();
-                // Set parameters as variables
-                for (param, val) in func.parameters.iter().zip(arg_values) {
-                    self.symbol_table
-                        .insert(param.name.clone(), param.param_type.clone());
-                    self.variables.insert(param.name.clone(), val);
-                }
-                // Execute body
-                let mut result = None;
-                for stmt in &func.body {
-                    let res = self.execute_statement(stmt)?;
-                    if let Some(val) = res {
-                        result = Some(val);
-                        break;
-                    }
-                }
-                // Pop scope
-                self.symbol_table.pop_scope();
-                // Remove param variables? But since scope popped, and variables are per interpreter, need to remove from variables too.
-                // Actually, variables are global, so need to remove the params.
-                for param in &func.parameters {
-                    self.variables.remove(&param.name);
-                }
-                result.ok_or_else(|| format!("Function '{}' did not return a value", call.name))
+            ExpressionKind::UnaryOp(op, operand) => {
+                let value = self.evaluate_expression(operand)?;
+                self.evaluate_unary_op(value, op)
+                    .map_err(|error| error.or_at(&expr.location))
             }
-            Expression::ArrayLiteral(array_lit) => {
+            ExpressionKind::FunctionCall(call) => self.call_function(call)?.ok_or_else(|| {
+                OtagError::runtime(
+                    RuntimeErrorKind::MissingReturnValue,
+                    format!(
+                        "Function '{}' did not return a value",
+                        call.name().unwrap_or_default()
+                    ),
+                    call.location.clone(),
+                )
+            }),
+            ExpressionKind::ArrayLiteral(array_lit) => {
                 let mut values = Vec::new();
                 for elem in &array_lit.elements {
                     values.push(self.evaluate_expression(elem)?);
                 }
                 Ok(VariableValue::Array(values))
             }
-            Expression::ArrayAccess(access) => {
+            ExpressionKind::ArrayAccess(access) => {
                 let array_val = self.evaluate_expression(&access.array)?;
                 let index_val = self.evaluate_expression(&access.index)?;
                 if let VariableValue::Array(arr) = array_val {
-                    if let VariableValue::Int(idx) = index_val {
-                        if idx >= 0 && (idx as usize) < arr.len() {
-                            Ok(arr[idx as usize].clone())
-                        } else {
-                            Err("Array index out of bounds".to_string())
-                        }
-                    } else {
-                        Err("Array index must be integer".to_string())
-                    }
+                    let position = element_position(&index_val, arr.len())
+                        .map_err(|error| error.or_at(&expr.location))?;
+                    Ok(arr[position].clone())
                 } else {
-                    Err("Not an array".to_string())
+                    Err(OtagError::runtime(
+                        RuntimeErrorKind::TypeMismatch,
+                        format!("Yalnızca diziler indekslenebilir, bulunan: {}", array_val),
+                        access.array.location.clone(),
+                    ))
                 }
             }
-            Expression::StructLiteral(_) => todo!(),
-            Expression::StructAccess(_) => todo!(),
+            ExpressionKind::StructLiteral(literal) => self
+                .evaluate_struct_literal(literal)
+                .map_err(|error| error.or_at(&expr.location)),
+            ExpressionKind::StructAccess(access) => self.evaluate_struct_access(access),
+        }
+    }
+
+    fn evaluate_struct_access(&mut self, access: &StructAccess) -> Result<VariableValue> {
+        let value = self.evaluate_expression(&access.struct_expr)?;
+        value.field(&access.field_name).cloned().ok_or_else(|| {
+            OtagError::runtime(
+                RuntimeErrorKind::UnknownField,
+                format!(
+                    "'{}' alanı okunamadı: {} bir yapı değil ya da böyle bir alanı yok",
+                    access.field_name, value
+                ),
+                access.struct_expr.location.clone(),
+            )
+        })
+    }
+
+    /// Build a struct value with its fields in definition order. Field
+    /// values are evaluated in the order they are written.
+    fn evaluate_struct_literal(&mut self, literal: &StructLiteral) -> Result<VariableValue> {
+        let mut values = Vec::new();
+        for field in &literal.fields {
+            values.push((field.name.clone(), self.evaluate_expression(&field.value)?));
+        }
+        let def = self
+            .symbol_table
+            .lookup_struct(&literal.struct_name)
+            .ok_or_else(|| {
+                runtime_error(
+                    RuntimeErrorKind::UndefinedStruct,
+                    format!("Tanımlanmamış yapı: {}", literal.struct_name),
+                )
+            })?;
+        let mut fields = Vec::new();
+        for field in &def.fields {
+            let index = values
+                .iter()
+                .position(|(name, _)| *name == field.name)
+                .ok_or_else(|| {
+                    runtime_error(
+                        RuntimeErrorKind::UnknownField,
+                        format!(
+                            "'{}' yapısının '{}' alanına değer verilmemiş",
+                            def.name, field.name
+                        ),
+                    )
+                })?;
+            fields.push(values.swap_remove(index));
+        }
+        Ok(VariableValue::Struct {
+            name: literal.struct_name.clone(),
+            fields,
+        })
+    }
+
+    /// Call a user-defined function. Yields `None` when the body finishes
+    /// without returning a value, as procedures do.
+    fn call_function(&mut self, call: &FunctionCall) -> Result<Option<VariableValue>> {
+        let name = call.name().ok_or_else(|| {
+            OtagError::runtime(
+                RuntimeErrorKind::TypeMismatch,
+                "Bu ifade çağrılamaz: yalnızca fonksiyonlar adlarıyla çağrılabilir".to_string(),
+                call.callee.location.clone(),
+            )
+        })?;
+        let func = self
+            .symbol_table
+            .lookup_function(name)
+            .ok_or_else(|| {
+                OtagError::runtime(
+                    RuntimeErrorKind::UndefinedFunction,
+                    format!("Undefined function: {}", name),
+                    call.location.clone(),
+                )
+            })?
+            .clone();
+        if call.arguments.len() != func.parameters.len() {
+            return Err(OtagError::runtime(
+                RuntimeErrorKind::ArgumentCountMismatch,
+                format!(
+                    "Function '{}' expects {} arguments, got {}",
+                    name,
+                    func.parameters.len(),
+                    call.arguments.len()
+                ),
+                call.location.clone(),
+            ));
+        }
+        let mut arg_values = Vec::new();
+        for arg in &call.arguments {
+            arg_values.push(self.evaluate_expression(arg)?);
+        }
+        if self.symbol_table.frame_depth() >= MAX_CALL_DEPTH {
+            return Err(OtagError::runtime(
+                RuntimeErrorKind::CallDepthExceeded,
+                format!(
+                    "'{}' çağrılırken iç içe fonksiyon çağrısı sınırı aşıldı ({} çağrı). Sonsuz özyineleme olabilir.",
+                    name,
+                    self.symbol_table.frame_depth()
+                ),
+                call.location.clone(),
+            ));
+        }
+        // Parameters are the first locals of a fresh call frame
+        self.symbol_table.push_frame();
+        for (param, val) in func.parameters.iter().zip(arg_values) {
+            self.symbol_table
+                .insert_with_value(param.name.clone(), param.param_type.clone(), val);
+        }
+        let result = stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT, || {
+            self.execute_function_body(&func.body)
+        });
+        self.symbol_table.pop_frame();
+        result.map_err(|error| error.in_call(name, &call.location))
+    }
+
+    /// Evaluate `ve` / `veya`, skipping the right operand when the left one
+    /// already decides the result
+    fn evaluate_logical_op(
+        &mut self,
+        left: &Expression,
+        op: &BinaryOperator,
+        right: &Expression,
+    ) -> Result<VariableValue> {
+        let operator = logical_operator_name(op);
+        let left_val = match self.evaluate_expression(left)? {
+            VariableValue::Bool(b) => b,
+            other => return Err(logical_operand_error(operator, other).or_at(&left.location)),
+        };
+        match (op, left_val) {
+            (BinaryOperator::And, false) => return Ok(VariableValue::Bool(false)),
+            (BinaryOperator::Or, true) => return Ok(VariableValue::Bool(true)),
+            _ => {}
+        }
+        match self.evaluate_expression(right)? {
+            VariableValue::Bool(b) => Ok(VariableValue::Bool(b)),
+            other => Err(logical_operand_error(operator, other).or_at(&right.location)),
+        }
+    }
+
+    fn evaluate_unary_op(&self, value: VariableValue, op: &UnaryOperator) -> Result<VariableValue> {
+        match (op, value) {
+            (UnaryOperator::Negate, VariableValue::Int(i)) => i
+                .checked_neg()
+                .map(VariableValue::Int)
+                .ok_or_else(overflow_error),
+            (UnaryOperator::Negate, VariableValue::Float(f)) => Ok(VariableValue::Float(-f)),
+            (UnaryOperator::Negate, other) => Err(runtime_error(
+                RuntimeErrorKind::TypeMismatch,
+                format!(
+                    "'-' işareti yalnızca sayılara uygulanabilir, bulunan: {:?}",
+                    other
+                ),
+            )),
+            (UnaryOperator::Not, VariableValue::Bool(b)) => Ok(VariableValue::Bool(!b)),
+            (UnaryOperator::Not, other) => Err(logical_operand_error("değil", other)),
         }
     }
 
@@ -195,38 +485,140 @@ impl Interpreter {
         left: VariableValue,
         right: VariableValue,
         op: &BinaryOperator,
-    ) -> Result<VariableValue, String> {
+    ) -> Result<VariableValue> {
         match op {
             BinaryOperator::Add => self.add_values(left, right),
+            BinaryOperator::Subtract => self.subtract_values(left, right),
+            BinaryOperator::Multiply => self.multiply_values(left, right),
+            BinaryOperator::Divide => self.divide_values(left, right),
+            BinaryOperator::Modulo => self.modulo_values(left, right),
+            BinaryOperator::Power => self.power_values(left, right),
             BinaryOperator::GreaterThan => self.compare_greater(left, right),
             BinaryOperator::GreaterThanOrEqual => self.compare_greater_equal(left, right),
             BinaryOperator::LessThan => self.compare_less(left, right),
             BinaryOperator::LessThanOrEqual => self.compare_less_equal(left, right),
+            BinaryOperator::Equal => self.values_equal(left, right).map(VariableValue::Bool),
+            BinaryOperator::NotEqual => self
+                .values_equal(left, right)
+                .map(|equal| VariableValue::Bool(!equal)),
+            // `ve` / `veya` normally short-circuit in evaluate_logical_op; with both
+            // operands already evaluated they reduce to plain boolean logic
+            BinaryOperator::And | BinaryOperator::Or => match (left, right) {
+                (VariableValue::Bool(l), VariableValue::Bool(r)) => {
+                    Ok(VariableValue::Bool(if matches!(op, BinaryOperator::And) {
+                        l && r
+                    } else {
+                        l || r
+                    }))
+                }
+                (VariableValue::Bool(_), other) | (other, _) => {
+                    Err(logical_operand_error(logical_operator_name(op), other))
+                }
+            },
         }
     }
 
-    fn add_values(
-        &self,
-        left: VariableValue,
-        right: VariableValue,
-    ) -> Result<VariableValue, String> {
+    /// Structural equality: values of the same type are compared field by
+    /// field and element by element, values of different types are an error
+    fn values_equal(&self, left: VariableValue, right: VariableValue) -> Result<bool> {
+        if std::mem::discriminant(&left) != std::mem::discriminant(&right) {
+            return Err(runtime_error(
+                RuntimeErrorKind::TypeMismatch,
+                format!(
+                    "Farklı türdeki değerler karşılaştırılamaz: {:?} ve {:?}",
+                    left, right
+                ),
+            ));
+        }
+        Ok(left == right)
+    }
+
+    fn add_values(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
         match (left, right) {
-            (VariableValue::Int(l), VariableValue::Int(r)) => Ok(VariableValue::Int(l + r)),
+            (VariableValue::Int(l), VariableValue::Int(r)) => {
+                l.checked_add(r).map(VariableValue::Int).ok_or_else(overflow_error)
+            }
             (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Float(l + r)),
             (VariableValue::String(l), VariableValue::String(r)) => Ok(VariableValue::String(l + &r)),
-            (left_val, right_val) => Err(format!("Cannot add values of types {:?} and {:?}. Addition is only supported between matching numeric types or strings.", left_val, right_val)),
+            (left_val, right_val) => Err(runtime_error(RuntimeErrorKind::TypeMismatch, format!("Cannot add values of types {:?} and {:?}. Addition is only supported between matching numeric types or strings.", left_val, right_val))),
         }
     }
 
-    fn compare_greater(
-        &self,
-        left: VariableValue,
-        right: VariableValue,
-    ) -> Result<VariableValue, String> {
+    fn subtract_values(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
+        match (left, right) {
+            (VariableValue::Int(l), VariableValue::Int(r)) => l
+                .checked_sub(r)
+                .map(VariableValue::Int)
+                .ok_or_else(overflow_error),
+            (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Float(l - r)),
+            (left_val, right_val) => Err(arithmetic_error("-", left_val, right_val)),
+        }
+    }
+
+    fn multiply_values(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
+        match (left, right) {
+            (VariableValue::Int(l), VariableValue::Int(r)) => l
+                .checked_mul(r)
+                .map(VariableValue::Int)
+                .ok_or_else(overflow_error),
+            (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Float(l * r)),
+            (left_val, right_val) => Err(arithmetic_error("*", left_val, right_val)),
+        }
+    }
+
+    fn divide_values(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
+        match (left, right) {
+            (VariableValue::Int(_), VariableValue::Int(0)) => {
+                Err(OtagError::division_by_zero(Location::unknown()))
+            }
+            (VariableValue::Int(l), VariableValue::Int(r)) => l
+                .checked_div(r)
+                .map(VariableValue::Int)
+                .ok_or_else(overflow_error),
+            (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Float(l / r)),
+            (left_val, right_val) => Err(arithmetic_error("/", left_val, right_val)),
+        }
+    }
+
+    fn modulo_values(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
+        match (left, right) {
+            (VariableValue::Int(_), VariableValue::Int(0)) => {
+                Err(OtagError::division_by_zero(Location::unknown()))
+            }
+            (VariableValue::Int(l), VariableValue::Int(r)) => l
+                .checked_rem(r)
+                .map(VariableValue::Int)
+                .ok_or_else(overflow_error),
+            (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Float(l % r)),
+            (left_val, right_val) => Err(arithmetic_error("%", left_val, right_val)),
+        }
+    }
+
+    fn power_values(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
+        match (left, right) {
+            (VariableValue::Int(_), VariableValue::Int(r)) if r < 0 => Err(runtime_error(
+                RuntimeErrorKind::NegativeExponent,
+                format!("Tamsayı üssü negatif olamaz: {}", r),
+            )
+            .with_suggestions(vec![
+                "Negatif üs için ondalıklı sayılar kullanın, örneğin 2.0 ** -1.0".to_string(),
+            ])),
+            (VariableValue::Int(l), VariableValue::Int(r)) => l
+                .checked_pow(r as u32)
+                .map(VariableValue::Int)
+                .ok_or_else(overflow_error),
+            (VariableValue::Float(l), VariableValue::Float(r)) => {
+                Ok(VariableValue::Float(l.powf(r)))
+            }
+            (left_val, right_val) => Err(arithmetic_error("**", left_val, right_val)),
+        }
+    }
+
+    fn compare_greater(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
         match (left, right) {
             (VariableValue::Int(l), VariableValue::Int(r)) => Ok(VariableValue::Bool(l > r)),
             (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Bool(l > r)),
-            (left_val, right_val) => Err(format!("Cannot compare values of types {:?} and {:?} with >. Comparison is only supported between matching numeric types.", left_val, right_val)),
+            (left_val, right_val) => Err(runtime_error(RuntimeErrorKind::TypeMismatch, format!("Cannot compare values of types {:?} and {:?} with >. Comparison is only supported between matching numeric types.", left_val, right_val))),
         }
     }
 
@@ -234,23 +626,19 @@ impl Interpreter {
         &self,
         left: VariableValue,
         right: VariableValue,
-    ) -> Result<VariableValue, String> {
+    ) -> Result<VariableValue> {
         match (left, right) {
             (VariableValue::Int(l), VariableValue::Int(r)) => Ok(VariableValue::Bool(l >= r)),
             (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Bool(l >= r)),
-            (left_val, right_val) => Err(format!("Cannot compare values of types {:?} and {:?} with >=. Comparison is only supported between matching numeric types.", left_val, right_val)),
+            (left_val, right_val) => Err(runtime_error(RuntimeErrorKind::TypeMismatch, format!("Cannot compare values of types {:?} and {:?} with >=. Comparison is only supported between matching numeric types.", left_val, right_val))),
         }
     }
 
-    fn compare_less(
-        &self,
-        left: VariableValue,
-        right: VariableValue,
-    ) -> Result<VariableValue, String> {
+    fn compare_less(&self, left: VariableValue, right: VariableValue) -> Result<VariableValue> {
         match (left, right) {
             (VariableValue::Int(l), VariableValue::Int(r)) => Ok(VariableValue::Bool(l < r)),
             (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Bool(l < r)),
-            (left_val, right_val) => Err(format!("Cannot compare values of types {:?} and {:?} with <. Comparison is only supported between matching numeric types.", left_val, right_val)),
+            (left_val, right_val) => Err(runtime_error(RuntimeErrorKind::TypeMismatch, format!("Cannot compare values of types {:?} and {:?} with <. Comparison is only supported between matching numeric types.", left_val, right_val))),
         }
     }
 
@@ -258,51 +646,73 @@ impl Interpreter {
         &self,
         left: VariableValue,
         right: VariableValue,
-    ) -> Result<VariableValue, String> {
+    ) -> Result<VariableValue> {
         match (left, right) {
             (VariableValue::Int(l), VariableValue::Int(r)) => Ok(VariableValue::Bool(l <= r)),
             (VariableValue::Float(l), VariableValue::Float(r)) => Ok(VariableValue::Bool(l <= r)),
-            (left_val, right_val) => Err(format!("Cannot compare values of types {:?} and {:?} with <=. Comparison is only supported between matching numeric types.", left_val, right_val)),
+            (left_val, right_val) => Err(runtime_error(RuntimeErrorKind::TypeMismatch, format!("Cannot compare values of types {:?} and {:?} with <=. Comparison is only supported between matching numeric types.", left_val, right_val))),
         }
     }
 
-    fn execute_control_block(&mut self, block: &ControlBlock) -> Result<(), String> {
-        for statement in &block.statements {
-            self.execute_statement(statement)?;
+    /// Execute statements in order, stopping at the first one that leaves the
+    /// block through `durdur`, `devam` or `return`
+    fn execute_control_block(&mut self, block: &ControlBlock) -> Result<ControlFlow> {
+        // Variables declared inside the block are dropped when it ends
+        self.symbol_table.push_scope();
+        let flow = self.execute_block_statements(&block.statements);
+        self.symbol_table.pop_scope();
+        flow
+    }
+
+    fn execute_block_statements(&mut self, statements: &[Statement]) -> Result<ControlFlow> {
+        for statement in statements {
+            let flow = self.execute_statement(statement)?;
+            if flow != ControlFlow::Normal {
+                return Ok(flow);
+            }
         }
-        Ok(())
+        Ok(ControlFlow::Normal)
     }
 
-    fn execute_if_statement(
-        &mut self,
-        if_stmt: &IfStatement,
-    ) -> Result<Option<VariableValue>, String> {
+    /// Run a function body in the current frame and yield its return value
+    fn execute_function_body(&mut self, body: &[Statement]) -> Result<Option<VariableValue>> {
+        match self.execute_block_statements(body)? {
+            ControlFlow::Return(value) => Ok(value),
+            _ => Ok(None),
+        }
+    }
+
+    fn execute_if_statement(&mut self, if_stmt: &IfStatement) -> Result<ControlFlow> {
         let condition_value = self.evaluate_expression(&if_stmt.condition.expression)?;
         if let VariableValue::Bool(cond) = condition_value {
             if cond {
-                self.execute_control_block(&if_stmt.then_block)?;
+                self.execute_control_block(&if_stmt.then_block)
             } else if let Some(else_block) = &if_stmt.else_block {
-                self.execute_control_block(else_block)?;
+                self.execute_control_block(else_block)
+            } else {
+                Ok(ControlFlow::Normal)
             }
-            Ok(None)
         } else {
-            Err("If condition must evaluate to a boolean".to_string())
+            Err(OtagError::runtime(
+                RuntimeErrorKind::TypeMismatch,
+                "If condition must evaluate to a boolean".to_string(),
+                if_stmt.condition.location.clone(),
+            ))
         }
     }
 
-    fn execute_while_loop(
-        &mut self,
-        while_loop: &WhileLoop,
-    ) -> Result<Option<VariableValue>, String> {
+    fn execute_while_loop(&mut self, while_loop: &WhileLoop) -> Result<ControlFlow> {
         let mut iterations = 0;
         const MAX_ITERATIONS: usize = 10000; // Prevent infinite loops
 
         loop {
             if iterations >= MAX_ITERATIONS {
-                return Err(
+                return Err(OtagError::runtime(
+                    RuntimeErrorKind::LoopLimitExceeded,
                     "While loop exceeded maximum iterations (10000). Possible infinite loop."
                         .to_string(),
-                );
+                    while_loop.location.clone(),
+                ));
             }
 
             let condition_value = self.evaluate_expression(&while_loop.condition.expression)?;
@@ -310,25 +720,124 @@ impl Interpreter {
                 if !cond {
                     break;
                 }
-                self.execute_control_block(&while_loop.body)?;
                 iterations += 1;
+                match self.execute_control_block(&while_loop.body)? {
+                    ControlFlow::Break => break,
+                    flow @ ControlFlow::Return(_) => return Ok(flow),
+                    ControlFlow::Normal | ControlFlow::Continue => {}
+                }
             } else {
-                return Err("While loop condition must evaluate to a boolean".to_string());
+                return Err(OtagError::runtime(
+                    RuntimeErrorKind::TypeMismatch,
+                    "While loop condition must evaluate to a boolean".to_string(),
+                    while_loop.condition.location.clone(),
+                ));
             }
         }
-        Ok(None)
+        Ok(ControlFlow::Normal)
     }
 
-    fn execute_for_loop(&mut self, _for_loop: &ForLoop) -> Result<Option<VariableValue>, String> {
-        todo!("Implement for loop execution")
+    fn execute_for_loop(&mut self, for_loop: &ForLoop) -> Result<ControlFlow> {
+        let start = self.evaluate_expression(&for_loop.range_start)?;
+        let end = self.evaluate_expression(&for_loop.range_end)?;
+        let step = match &for_loop.step {
+            Some(step) => self.evaluate_expression(step)?,
+            None => VariableValue::Int(1),
+        };
+        let step_location = for_loop
+            .step
+            .as_ref()
+            .map_or(&for_loop.location, |step| &step.location);
+        let range = CountedRange::new(start, end, step, &for_loop.location, step_location)?;
+        let loop_type = match range {
+            CountedRange::Int { .. } => Type::Tamsayi,
+            CountedRange::Float { .. } => Type::Ondalikli,
+        };
+
+        // The loop variable lives in its own scope and hides any outer variable
+        // with the same name until the loop ends
+        let name = &for_loop.loop_variable.name;
+        self.symbol_table.push_scope();
+        let flow = self.run_counted_loop(name, loop_type, range, &for_loop.body);
+        self.symbol_table.pop_scope();
+        flow
     }
 
-    fn execute_break(&mut self) -> Result<Option<VariableValue>, String> {
-        todo!("Implement break statement")
+    fn run_counted_loop(
+        &mut self,
+        name: &str,
+        loop_type: Type,
+        range: CountedRange,
+        body: &ControlBlock,
+    ) -> Result<ControlFlow> {
+        for value in range {
+            self.symbol_table
+                .insert_with_value(name.to_string(), loop_type.clone(), value);
+            match self.execute_control_block(body)? {
+                ControlFlow::Break => break,
+                flow @ ControlFlow::Return(_) => return Ok(flow),
+                ControlFlow::Normal | ControlFlow::Continue => {}
+            }
+        }
+        Ok(ControlFlow::Normal)
     }
 
-    fn execute_continue(&mut self) -> Result<Option<VariableValue>, String> {
-        todo!("Implement continue statement")
+    fn execute_for_each_loop(&mut self, for_each: &ForEachLoop) -> Result<ControlFlow> {
+        let elements = match self.evaluate_expression(&for_each.iterable)? {
+            VariableValue::Array(elements) => elements,
+            VariableValue::String(text) => text
+                .chars()
+                .map(|c| VariableValue::String(c.to_string()))
+                .collect(),
+            other => {
+                return Err(OtagError::runtime(
+                    RuntimeErrorKind::TypeMismatch,
+                    format!("{} üzerinde döngü kurulamaz", other),
+                    for_each.location.clone(),
+                ))
+            }
+        };
+
+        // Like counted loops, the loop variables get their own scope
+        self.symbol_table.push_scope();
+        let flow = self.run_for_each_loop(for_each, elements);
+        self.symbol_table.pop_scope();
+        flow
+    }
+
+    fn run_for_each_loop(
+        &mut self,
+        for_each: &ForEachLoop,
+        elements: Vec<VariableValue>,
+    ) -> Result<ControlFlow> {
+        for (index, element) in elements.into_iter().enumerate() {
+            if let Some(name) = &for_each.index_variable {
+                self.symbol_table.insert_with_value(
+                    name.clone(),
+                    Type::Tamsayi,
+                    VariableValue::Int(index as i32),
+                );
+            }
+            self.symbol_table.insert_with_value(
+                for_each.loop_variable.name.clone(),
+                element.value_type(),
+                element,
+            );
+            match self.execute_control_block(&for_each.body)? {
+                ControlFlow::Break => break,
+                flow @ ControlFlow::Return(_) => return Ok(flow),
+                ControlFlow::Normal | ControlFlow::Continue => {}
+            }
+        }
+        Ok(ControlFlow::Normal)
+    }
+
+    fn execute_break(&mut self) -> Result<ControlFlow> {
+        Ok(ControlFlow::Break)
+    }
+
+    fn execute_continue(&mut self) -> Result<ControlFlow> {
+        Ok(ControlFlow::Continue)
     }
 }
 
@@ -338,41 +847,229 @@ impl Default for Interpreter {
     }
 }
 
+/// Values taken by the variable of a counted `için` loop. Both bounds are
+/// inclusive; a negative step counts down.
+enum CountedRange {
+    Int {
+        next: i64,
+        end: i64,
+        step: i64,
+    },
+    Float {
+        start: f64,
+        end: f64,
+        step: f64,
+        index: u64,
+    },
+}
+
+impl CountedRange {
+    fn new(
+        start: VariableValue,
+        end: VariableValue,
+        step: VariableValue,
+        location: &Location,
+        step_location: &Location,
+    ) -> Result<Self> {
+        let zero_step_error = || {
+            OtagError::runtime(
+                RuntimeErrorKind::ZeroStep,
+                "Döngü adımı sıfır olamaz".to_string(),
+                step_location.clone(),
+            )
+            .with_suggestions(vec![
+                "Artan bir döngü için pozitif, azalan bir döngü için negatif adım kullanın"
+                    .to_string(),
+                "Örnek: için i in 10 dan 1 adım -1 ise".to_string(),
+            ])
+        };
+        match (start, end, step) {
+            (VariableValue::Int(_), VariableValue::Int(_), VariableValue::Int(0)) => {
+                Err(zero_step_error())
+            }
+            (VariableValue::Int(start), VariableValue::Int(end), VariableValue::Int(step)) => {
+                Ok(CountedRange::Int {
+                    next: start.into(),
+                    end: end.into(),
+                    step: step.into(),
+                })
+            }
+            // Any floating point bound or step turns the whole loop into a float loop
+            (start, end, step) => match (as_float(&start), as_float(&end), as_float(&step)) {
+                (Some(_), Some(_), Some(0.0)) => Err(zero_step_error()),
+                (Some(start), Some(end), Some(step)) => Ok(CountedRange::Float {
+                    start,
+                    end,
+                    step,
+                    index: 0,
+                }),
+                _ => Err(OtagError::runtime(
+                    RuntimeErrorKind::TypeMismatch,
+                    format!(
+                        "Döngü sınırları ve adımı sayı olmalıdır, bulunan: {:?}, {:?}, {:?}",
+                        start, end, step
+                    ),
+                    location.clone(),
+                )),
+            },
+        }
+    }
+}
+
+impl Iterator for CountedRange {
+    type Item = VariableValue;
+
+    fn next(&mut self) -> Option<VariableValue> {
+        match self {
+            CountedRange::Int { next, end, step } => {
+                let in_range = if *step > 0 {
+                    *next <= *end
+                } else {
+                    *next >= *end
+                };
+                if !in_range {
+                    return None;
+                }
+                let value = *next as i32;
+                *next += *step;
+                Some(VariableValue::Int(value))
+            }
+            CountedRange::Float {
+                start,
+                end,
+                step,
+                index,
+            } => {
+                // Computing each value from the start avoids accumulating rounding
+                // errors, and the tolerance lets `0.0 dan 1.0 adım 0.1` reach 1.0
+                let value = *start + *step * *index as f64;
+                let tolerance = step.abs() * 1e-9;
+                let in_range = if *step > 0.0 {
+                    value <= *end + tolerance
+                } else {
+                    value >= *end - tolerance
+                };
+                if !in_range {
+                    return None;
+                }
+                *index += 1;
+                Some(VariableValue::Float(value))
+            }
+        }
+    }
+}
+
+fn as_float(value: &VariableValue) -> Option<f64> {
+    match value {
+        VariableValue::Int(i) => Some(f64::from(*i)),
+        VariableValue::Float(f) => Some(*f),
+        _ => None,
+    }
+}
+
+/// Position of the element `index` refers to in an array of `len` elements
+fn element_position(index: &VariableValue, len: usize) -> Result<usize> {
+    match index {
+        VariableValue::Int(i) if *i >= 0 && (*i as usize) < len => Ok(*i as usize),
+        VariableValue::Int(i) => Err(runtime_error(
+            RuntimeErrorKind::IndexOutOfBounds,
+            format!(
+                "Dizi sınırlarının dışında: {} indeksi, dizinin uzunluğu {}",
+                i, len
+            ),
+        )),
+        other => Err(runtime_error(
+            RuntimeErrorKind::TypeMismatch,
+            format!("Dizi indeksi tamsayı olmalıdır, bulunan: {}", other),
+        )),
+    }
+}
+
+fn runtime_error(kind: RuntimeErrorKind, message: String) -> OtagError {
+    OtagError::runtime(kind, message, Location::unknown())
+}
+
+fn overflow_error() -> OtagError {
+    runtime_error(
+        RuntimeErrorKind::IntegerOverflow,
+        "Tamsayı taşması: sonuç tamsayı sınırlarının dışında".to_string(),
+    )
+}
+
+fn logical_operator_name(op: &BinaryOperator) -> &'static str {
+    if matches!(op, BinaryOperator::And) {
+        "ve"
+    } else {
+        "veya"
+    }
+}
+
+fn logical_operand_error(operator: &str, value: VariableValue) -> OtagError {
+    runtime_error(
+        RuntimeErrorKind::TypeMismatch,
+        format!(
+            "'{}' işlemi yalnızca mantıksal değerlerle yapılabilir, bulunan: {:?}",
+            operator, value
+        ),
+    )
+}
+
+fn arithmetic_error(operator: &str, left: VariableValue, right: VariableValue) -> OtagError {
+    runtime_error(RuntimeErrorKind::TypeMismatch, format!(
+        "'{}' işlemi {:?} ve {:?} değerleri arasında yapılamaz. Aritmetik yalnızca aynı türdeki sayılar arasında desteklenir.",
+        operator, left, right
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn expr(kind: ExpressionKind) -> Expression {
+        Expression::new(kind, Location::unknown())
+    }
+
     #[test]
     fn test_execute_if_statement() {
         let mut interpreter = Interpreter::new();
         // Declare x = 10
         let decl = VariableDeclaration {
+            location: Location::unknown(),
             name: "x".to_string(),
-            var_type: Type::Tamsayi,
+            suffix: None,
+            var_type: Some(Type::Tamsayi),
+            initializer: None,
         };
         interpreter.execute_variable_declaration(&decl).unwrap();
         interpreter
             .execute_assignment(&Assignment {
+                location: Location::unknown(),
                 name: "x".to_string(),
-                expression: Expression::Literal(VariableValue::Int(10)),
+                accessors: Vec::new(),
+                expression: expr(ExpressionKind::Literal(VariableValue::Int(10))),
             })
             .unwrap();
 
         // If x > 5 then output "Büyük"
         let if_stmt = IfStatement {
             condition: Condition {
-                expression: Box::new(Expression::BinaryOp(
-                    Box::new(Expression::VariableRef("x".to_string())),
+                location: Location::unknown(),
+                expression: Box::new(expr(ExpressionKind::BinaryOp(
+                    Box::new(expr(ExpressionKind::VariableRef("x".to_string()))),
                     BinaryOperator::GreaterThan,
-                    Box::new(Expression::Literal(VariableValue::Int(5))),
-                )),
+                    Box::new(expr(ExpressionKind::Literal(VariableValue::Int(5)))),
+                ))),
             },
             then_block: ControlBlock {
                 statements: vec![Statement::Output(OutputStatement {
-                    expression: Expression::Literal(VariableValue::String("Büyük".to_string())),
+                    location: Location::unknown(),
+                    expression: expr(ExpressionKind::Literal(VariableValue::String(
+                        "Büyük".to_string(),
+                    ))),
                 })],
             },
             else_block: None,
+            location: Location::unknown(),
         };
 
         interpreter.execute_if_statement(&if_stmt).unwrap();
@@ -384,44 +1081,166 @@ mod tests {
         let mut interpreter = Interpreter::new();
         // Declare counter = 0
         let decl = VariableDeclaration {
+            location: Location::unknown(),
             name: "counter".to_string(),
-            var_type: Type::Tamsayi,
+            suffix: None,
+            var_type: Some(Type::Tamsayi),
+            initializer: None,
         };
         interpreter.execute_variable_declaration(&decl).unwrap();
         interpreter
             .execute_assignment(&Assignment {
+                location: Location::unknown(),
                 name: "counter".to_string(),
-                expression: Expression::Literal(VariableValue::Int(0)),
+                accessors: Vec::new(),
+                expression: expr(ExpressionKind::Literal(VariableValue::Int(0))),
             })
             .unwrap();
 
         // While counter < 3, increment counter and output its value
         let while_loop = WhileLoop {
             condition: Condition {
-                expression: Box::new(Expression::BinaryOp(
-                    Box::new(Expression::VariableRef("counter".to_string())),
+                location: Location::unknown(),
+                expression: Box::new(expr(ExpressionKind::BinaryOp(
+                    Box::new(expr(ExpressionKind::VariableRef("counter".to_string()))),
                     BinaryOperator::LessThan,
-                    Box::new(Expression::Literal(VariableValue::Int(3))),
-                )),
+                    Box::new(expr(ExpressionKind::Literal(VariableValue::Int(3)))),
+                ))),
             },
             body: ControlBlock {
                 statements: vec![
                     Statement::Output(OutputStatement {
-                        expression: Expression::VariableRef("counter".to_string()),
+                        location: Location::unknown(),
+                        expression: expr(ExpressionKind::VariableRef("counter".to_string())),
                     }),
                     Statement::Assignment(Assignment {
+                        location: Location::unknown(),
                         name: "counter".to_string(),
-                        expression: Expression::BinaryOp(
-                            Box::new(Expression::VariableRef("counter".to_string())),
+                        accessors: Vec::new(),
+                        expression: expr(ExpressionKind::BinaryOp(
+                            Box::new(expr(ExpressionKind::VariableRef("counter".to_string()))),
                             BinaryOperator::Add,
-                            Box::new(Expression::Literal(VariableValue::Int(1))),
-                        ),
+                            Box::new(expr(ExpressionKind::Literal(VariableValue::Int(1)))),
+                        )),
                     }),
                 ],
             },
+            location: Location::unknown(),
         };
 
         interpreter.execute_while_loop(&while_loop).unwrap();
         // Should have printed 0, 1, 2
     }
+
+    fn int_array(values: &[i32]) -> VariableValue {
+        VariableValue::Array(values.iter().map(|v| VariableValue::Int(*v)).collect())
+    }
+
+    fn assign_index(name: &str, index: i32, value: i32) -> Assignment {
+        Assignment {
+            location: Location::new("test.otağ".to_string(), 4, 1),
+            name: name.to_string(),
+            accessors: vec![Accessor::Index(expr(ExpressionKind::Literal(
+                VariableValue::Int(index),
+            )))],
+            expression: expr(ExpressionKind::Literal(VariableValue::Int(value))),
+        }
+    }
+
+    #[test]
+    fn test_assign_array_element_in_place() {
+        let mut interpreter = Interpreter::new();
+        interpreter.symbol_table.insert_with_value(
+            "sayılar".to_string(),
+            Type::Array(Box::new(Type::Tamsayi)),
+            int_array(&[1, 2, 3]),
+        );
+        interpreter
+            .execute_assignment(&assign_index("sayılar", 1, 10))
+            .unwrap();
+        assert_eq!(
+            interpreter.symbol_table.lookup("sayılar").unwrap().value,
+            Some(int_array(&[1, 10, 3]))
+        );
+    }
+
+    #[test]
+    fn test_assign_field_of_array_element() {
+        let mut interpreter = Interpreter::new();
+        let student = |grade| VariableValue::Struct {
+            name: "Öğrenci".to_string(),
+            fields: vec![("not".to_string(), VariableValue::Int(grade))],
+        };
+        interpreter.symbol_table.insert_with_value(
+            "öğrenciler".to_string(),
+            Type::Array(Box::new(Type::Struct("Öğrenci".to_string()))),
+            VariableValue::Array(vec![student(50), student(60)]),
+        );
+        interpreter
+            .execute_assignment(&Assignment {
+                location: Location::unknown(),
+                name: "öğrenciler".to_string(),
+                accessors: vec![
+                    Accessor::Index(expr(ExpressionKind::Literal(VariableValue::Int(1)))),
+                    Accessor::Field("not".to_string()),
+                ],
+                expression: expr(ExpressionKind::Literal(VariableValue::Int(90))),
+            })
+            .unwrap();
+        assert_eq!(
+            interpreter.symbol_table.lookup("öğrenciler").unwrap().value,
+            Some(VariableValue::Array(vec![student(50), student(90)]))
+        );
+    }
+
+    #[test]
+    fn test_assign_out_of_bounds_is_located_error() {
+        let mut interpreter = Interpreter::new();
+        interpreter.symbol_table.insert_with_value(
+            "sayılar".to_string(),
+            Type::Array(Box::new(Type::Tamsayi)),
+            int_array(&[1, 2, 3]),
+        );
+        let err = interpreter
+            .execute_assignment(&assign_index("sayılar", 3, 10))
+            .unwrap_err();
+        assert_eq!(
+            err.message,
+            "Dizi sınırlarının dışında: 3 indeksi, dizinin uzunluğu 3"
+        );
+        assert_eq!(err.location.line, 4);
+    }
+
+    #[test]
+    fn test_integer_division_by_zero() {
+        let interpreter = Interpreter::new();
+        let err = interpreter
+            .evaluate_binary_op(
+                VariableValue::Int(10),
+                VariableValue::Int(0),
+                &BinaryOperator::Divide,
+            )
+            .unwrap_err();
+        assert_eq!(err.message, "Sıfıra bölme hatası");
+
+        let err = interpreter
+            .evaluate_binary_op(
+                VariableValue::Int(10),
+                VariableValue::Int(0),
+                &BinaryOperator::Modulo,
+            )
+            .unwrap_err();
+        assert_eq!(err.message, "Sıfıra bölme hatası");
+    }
+
+    #[test]
+    fn test_integer_overflow_is_an_error() {
+        let interpreter = Interpreter::new();
+        let result = interpreter.evaluate_binary_op(
+            VariableValue::Int(i32::MAX),
+            VariableValue::Int(1),
+            &BinaryOperator::Add,
+        );
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,503 @@
+//! Rendering errors for the terminal.
+//!
+//! A rendered diagnostic shows the offending source lines under a line-number
+//! gutter, with `^` under the primary span and `-` under each secondary label:
+//!
+//! ```text
+//! hata: Tanımlanmamış değişken: yok
+//!  --> ana.otağ:2:11
+//!   |
+//! 2 | söyle x + yok
+//!   |           ^^^
+//! ```
+//!
+//! A run reports all of its problems at once through `Diagnostics`, ending
+//! with a count such as "3 hata, 2 uyarı".
+
+use crate::error_reporting::{ErrorType, OtagError};
+use crate::location::Location;
+use std::collections::HashMap;
+
+/// Source text of every loaded file, keyed by the file name its locations use
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: HashMap<String, String>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, file: impl Into<String>, source: impl Into<String>) {
+        self.files.insert(file.into(), source.into());
+    }
+
+    pub fn get(&self, file: &str) -> Option<&str> {
+        self.files.get(file).map(String::as_str)
+    }
+}
+
+/// Most diagnostics shown for one run; the summary still counts the rest
+pub const MAX_DIAGNOSTICS: usize = 20;
+
+/// Every error and warning found in a run, sorted by location
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    items: Vec<OtagError>,
+    error_count: usize,
+    warning_count: usize,
+}
+
+impl Diagnostics {
+    /// Sort `items` by file, in the order files first appear, then by
+    /// position. At most `MAX_DIAGNOSTICS` are kept, errors before warnings,
+    /// so a run with errors always shows them.
+    pub fn new(items: Vec<OtagError>) -> Self {
+        let mut files: Vec<String> = Vec::new();
+        for item in &items {
+            if !files.contains(&item.location.file) {
+                files.push(item.location.file.clone());
+            }
+        }
+        let position = |item: &OtagError| {
+            let file = files.iter().position(|file| *file == item.location.file);
+            (file, item.location.line, item.location.column)
+        };
+        let (mut errors, mut warnings): (Vec<_>, Vec<_>) = items
+            .into_iter()
+            .partition(|item| item.error_type != ErrorType::Warning);
+        let error_count = errors.len();
+        let warning_count = warnings.len();
+
+        errors.sort_by_key(position);
+        warnings.sort_by_key(position);
+        errors.truncate(MAX_DIAGNOSTICS);
+        warnings.truncate(MAX_DIAGNOSTICS - errors.len());
+        let mut items = errors;
+        items.append(&mut warnings);
+        items.sort_by_key(position);
+        Self {
+            items,
+            error_count,
+            warning_count,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_count > 0
+    }
+
+    #[allow(dead_code)]
+    pub fn error_count(&self) -> usize {
+        self.error_count
+    }
+
+    #[allow(dead_code)]
+    pub fn warning_count(&self) -> usize {
+        self.warning_count
+    }
+
+    /// The diagnostics to show, errors and warnings together
+    #[allow(dead_code)]
+    pub fn items(&self) -> &[OtagError] {
+        &self.items
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &OtagError> {
+        self.items
+            .iter()
+            .filter(|item| item.error_type != ErrorType::Warning)
+    }
+
+    #[allow(dead_code)]
+    pub fn warnings(&self) -> impl Iterator<Item = &OtagError> {
+        self.items
+            .iter()
+            .filter(|item| item.error_type == ErrorType::Warning)
+    }
+
+    /// Counts line such as "3 hata, 2 uyarı"
+    pub fn summary(&self) -> String {
+        let mut counts = Vec::new();
+        if self.error_count > 0 {
+            counts.push(format!("{} hata", self.error_count));
+        }
+        if self.warning_count > 0 {
+            counts.push(format!("{} uyarı", self.warning_count));
+        }
+        let mut summary = counts.join(", ");
+        if self.error_count + self.warning_count > self.items.len() {
+            summary.push_str(&format!(" (ilk {} tanesi gösterildi)", self.items.len()));
+        }
+        summary
+    }
+
+    /// Render every diagnostic followed by the summary line
+    pub fn render(&self, sources: &SourceMap, color: bool) -> String {
+        let painter = Painter { color };
+        let mut out: Vec<String> = self
+            .items
+            .iter()
+            .map(|item| render(item, sources, color))
+            .collect();
+        out.push(painter.paint(BOLD, &self.summary()));
+        out.join("\n\n")
+    }
+}
+
+const TAB_WIDTH: usize = 4;
+
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Wraps text in ANSI styles, or leaves it alone when colour is off
+struct Painter {
+    color: bool,
+}
+
+impl Painter {
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color && !text.is_empty() {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// A span to underline and the text written after it
+struct Annotation<'a> {
+    location: &'a Location,
+    message: &'a str,
+    primary: bool,
+}
+
+/// Render `error` with the source lines it points at. Files missing from
+/// `sources` are shown by location only.
+pub fn render(error: &OtagError, sources: &SourceMap, color: bool) -> String {
+    let painter = Painter { color };
+    let (title, style) = match error.error_type {
+        ErrorType::Syntax => ("sözdizimi hatası", RED),
+        ErrorType::Semantic => ("hata", RED),
+        ErrorType::Runtime(_) => ("çalışma zamanı hatası", RED),
+        ErrorType::Warning => ("uyarı", YELLOW),
+    };
+    let mut out = format!(
+        "{}{}",
+        painter.paint(style, title),
+        painter.paint(BOLD, &format!(": {}", error.message))
+    );
+
+    // One snippet per file, the primary location's file first
+    let mut groups: Vec<(&str, Vec<Annotation>)> = Vec::new();
+    let primary = Annotation {
+        location: &error.location,
+        message: "",
+        primary: true,
+    };
+    let secondary = error.labels.iter().map(|label| Annotation {
+        location: &label.location,
+        message: &label.message,
+        primary: false,
+    });
+    for annotation in std::iter::once(primary).chain(secondary) {
+        if annotation.location.file == Location::unknown().file {
+            continue;
+        }
+        match groups
+            .iter_mut()
+            .find(|(file, _)| *file == annotation.location.file)
+        {
+            Some((_, annotations)) => annotations.push(annotation),
+            None => groups.push((&annotation.location.file, vec![annotation])),
+        }
+    }
+
+    let width = groups
+        .iter()
+        .flat_map(|(_, annotations)| annotations.iter())
+        .map(|annotation| annotation.location.line.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let gutter = |number: &str| painter.paint(BLUE, &format!("{:>width$} |", number));
+
+    for (index, (file, annotations)) in groups.iter().enumerate() {
+        let arrow = if index == 0 { "-->" } else { ":::" };
+        let first = annotations[0].location;
+        let position = if first.line == 0 {
+            file.to_string()
+        } else {
+            first.to_string()
+        };
+        out.push_str(&format!(
+            "\n{}{} {}",
+            " ".repeat(width),
+            painter.paint(BLUE, arrow),
+            position
+        ));
+
+        let Some(source) = sources.get(file) else {
+            continue;
+        };
+        let lines: Vec<&str> = source.lines().collect();
+        let mut numbers: Vec<usize> = annotations
+            .iter()
+            .map(|annotation| annotation.location.line)
+            .filter(|&line| line >= 1 && line <= lines.len())
+            .collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+        if numbers.is_empty() {
+            continue;
+        }
+
+        out.push_str(&format!("\n{}", gutter("")));
+        let mut previous = None;
+        for number in numbers {
+            if previous.is_some_and(|previous| number > previous + 1) {
+                out.push_str(&format!("\n{}", painter.paint(BLUE, "...")));
+            }
+            previous = Some(number);
+
+            let line = lines[number - 1];
+            out.push_str(&format!(
+                "\n{} {}",
+                gutter(&number.to_string()),
+                line.replace('\t', &" ".repeat(TAB_WIDTH))
+            ));
+
+            let mut on_line: Vec<&Annotation> = annotations
+                .iter()
+                .filter(|annotation| annotation.location.line == number)
+                .collect();
+            on_line.sort_by_key(|annotation| annotation.location.column);
+            for annotation in on_line {
+                let (offset, length) = underline(source, line, annotation.location);
+                let (mark, style) = if annotation.primary {
+                    ('^', style)
+                } else {
+                    ('-', BLUE)
+                };
+                let mut text = mark.to_string().repeat(length);
+                if !annotation.message.is_empty() {
+                    text = format!("{} {}", text, annotation.message);
+                }
+                out.push_str(&format!(
+                    "\n{} {}{}",
+                    gutter(""),
+                    " ".repeat(offset),
+                    painter.paint(style, &text)
+                ));
+            }
+        }
+    }
+
+    for line in error.trace_lines() {
+        out.push_str(&format!(
+            "\n{} {} {}",
+            " ".repeat(width),
+            painter.paint(BLUE, "="),
+            line
+        ));
+    }
+    for suggestion in &error.suggestions {
+        out.push_str(&format!(
+            "\n{} {} {}",
+            " ".repeat(width),
+            painter.paint(BLUE, "="),
+            painter.paint(BOLD, &format!("öneri: {}", suggestion))
+        ));
+    }
+    out
+}
+
+/// Display offset and length of the underline for `location` on `line`. A
+/// span running onto later lines is underlined to the end of its first line.
+fn underline(source: &str, line: &str, location: &Location) -> (usize, usize) {
+    let before: Vec<char> = line
+        .chars()
+        .take(location.column.saturating_sub(1))
+        .collect();
+    let offset = display_width(before.iter().copied());
+
+    let rest = &line[before.iter().map(|c| c.len_utf8()).sum::<usize>()..];
+    let spanned = source
+        .get(location.start..location.end)
+        .and_then(|text| text.lines().next())
+        .unwrap_or("");
+    let length = display_width(rest.chars().take(spanned.chars().count()));
+    (offset, length.max(1))
+}
+
+fn display_width(chars: impl Iterator<Item = char>) -> usize {
+    chars.map(|c| if c == '\t' { TAB_WIDTH } else { 1 }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_reporting::RuntimeErrorKind;
+
+    fn sources(file: &str, source: &str) -> SourceMap {
+        let mut sources = SourceMap::new();
+        sources.add(file, source);
+        sources
+    }
+
+    fn span(file: &str, source: &str, text: &str) -> Location {
+        let start = source.find(text).unwrap();
+        let mut location = Location::from_position(source, start, file.to_string());
+        location.end = start + text.len();
+        location
+    }
+
+    #[test]
+    fn test_render_underlines_the_span() {
+        let source = "değişken x = 1\nsöyle x + yok\n";
+        let error = OtagError::semantic(
+            "Tanımlanmamış değişken: yok".to_string(),
+            span("ana.otağ", source, "yok"),
+        );
+        let rendered = render(&error, &sources("ana.otağ", source), false);
+        assert_eq!(
+            rendered,
+            "hata: Tanımlanmamış değişken: yok\n \
+             --> ana.otağ:2:11\n  \
+             |\n\
+             2 | söyle x + yok\n  \
+             |           ^^^"
+        );
+    }
+
+    #[test]
+    fn test_render_secondary_label_and_suggestion() {
+        let source = "fonksiyon f() {\n}\n\nfonksiyon f() {\n}\n";
+        let first = span("a.otağ", source, "fonksiyon f() {\n}");
+        let second_start = source.rfind("fonksiyon").unwrap();
+        let mut second = Location::from_position(source, second_start, "a.otağ".to_string());
+        second.end = source.len() - 1;
+        let error = OtagError::semantic("'f' iki kez tanımlanmış".to_string(), second)
+            .with_label(&first, "ilk tanım burada")
+            .with_suggestions(vec!["Birini silin".to_string()]);
+        let rendered = render(&error, &sources("a.otağ", source), false);
+        assert_eq!(
+            rendered,
+            "hata: 'f' iki kez tanımlanmış\n \
+             --> a.otağ:4:1\n  \
+             |\n\
+             1 | fonksiyon f() {\n  \
+             | --------------- ilk tanım burada\n\
+             ...\n\
+             4 | fonksiyon f() {\n  \
+             | ^^^^^^^^^^^^^^^\n  \
+             = öneri: Birini silin"
+        );
+    }
+
+    #[test]
+    fn test_render_without_source_shows_location_only() {
+        let error = OtagError::runtime(
+            RuntimeErrorKind::ImportFailed,
+            "Dosya bulunamadı: yok.otağ".to_string(),
+            Location::new("yok.otağ".to_string(), 0, 0),
+        );
+        assert_eq!(
+            render(&error, &SourceMap::new(), false),
+            "çalışma zamanı hatası: Dosya bulunamadı: yok.otağ\n --> yok.otağ"
+        );
+
+        let error = OtagError::division_by_zero(Location::unknown());
+        assert_eq!(
+            render(&error, &SourceMap::new(), false),
+            "çalışma zamanı hatası: Sıfıra bölme hatası"
+        );
+    }
+
+    #[test]
+    fn test_render_colour_and_tabs() {
+        let source = "\tsöyle yok";
+        let error = OtagError::warning("uyarı".to_string(), span("t.otağ", source, "yok"));
+        let plain = render(&error, &sources("t.otağ", source), false);
+        assert!(
+            plain.ends_with("1 |     söyle yok\n  |           ^^^"),
+            "{}",
+            plain
+        );
+        assert!(!plain.contains('\x1b'));
+
+        let colored = render(&error, &sources("t.otağ", source), true);
+        assert!(
+            colored.starts_with("\x1b[1;33muyarı\x1b[0m"),
+            "{:?}",
+            colored
+        );
+        assert!(colored.contains("\x1b[1;33m^^^\x1b[0m"));
+    }
+
+    fn error_at(line: usize, column: usize) -> OtagError {
+        OtagError::semantic(
+            format!("{}:{}", line, column),
+            Location::new("a.otağ".to_string(), line, column),
+        )
+    }
+
+    #[test]
+    fn test_diagnostics_sorted_and_counted() {
+        let warning =
+            OtagError::warning("w".to_string(), Location::new("a.otağ".to_string(), 2, 1));
+        let other_file =
+            OtagError::semantic("b".to_string(), Location::new("b.otağ".to_string(), 1, 1));
+        let diagnostics = Diagnostics::new(vec![
+            error_at(3, 1),
+            other_file,
+            warning,
+            error_at(1, 5),
+            error_at(1, 2),
+        ]);
+        let messages: Vec<&str> = diagnostics
+            .items()
+            .iter()
+            .map(|item| item.message.as_str())
+            .collect();
+        assert_eq!(messages, vec!["1:2", "1:5", "w", "3:1", "b"]);
+        assert_eq!(diagnostics.errors().count(), 4);
+        assert_eq!(diagnostics.summary(), "4 hata, 1 uyarı");
+    }
+
+    #[test]
+    fn test_capped_diagnostics_keep_errors_before_warnings() {
+        let warnings = (1..=25).map(|line| {
+            OtagError::warning(
+                "w".to_string(),
+                Location::new("a.otağ".to_string(), line, 1),
+            )
+        });
+        let diagnostics = Diagnostics::new(warnings.chain([error_at(30, 1)]).collect());
+        assert_eq!(diagnostics.items().len(), MAX_DIAGNOSTICS);
+        assert_eq!(diagnostics.errors().count(), 1);
+        assert_eq!(diagnostics.items().last().unwrap().location.line, 30);
+        assert_eq!(
+            diagnostics.summary(),
+            "1 hata, 25 uyarı (ilk 20 tanesi gösterildi)"
+        );
+    }
+
+    #[test]
+    fn test_diagnostics_are_capped() {
+        let diagnostics = Diagnostics::new((1..=25).map(|line| error_at(line, 1)).collect());
+        assert_eq!(diagnostics.items().len(), MAX_DIAGNOSTICS);
+        assert_eq!(diagnostics.error_count(), 25);
+        assert_eq!(diagnostics.summary(), "25 hata (ilk 20 tanesi gösterildi)");
+        assert!(diagnostics
+            .render(&SourceMap::new(), false)
+            .ends_with("hata: 20:1\n  --> a.otağ:20:1\n\n25 hata (ilk 20 tanesi gösterildi)"));
+    }
+}
@@ -4,16 +4,74 @@ use crate::location::Location;
 pub enum ErrorType {
     Syntax,
     Semantic,
-    Runtime,
+    Runtime(RuntimeErrorKind),
+    /// Does not stop the program, e.g. a misspelled suffix
+    Warning,
 }
 
+/// What went wrong while loading or running a program. Lets callers tell
+/// runtime errors apart without matching on the Turkish message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeErrorKind {
+    /// A source file could not be found, read or resolved
+    ImportFailed,
+    DivisionByZero,
+    /// Result outside the range of `tamsayı`
+    IntegerOverflow,
+    /// `tamsayı` raised to a negative power
+    NegativeExponent,
+    /// A value of the wrong type for an operator, condition, index or loop
+    TypeMismatch,
+    UndefinedVariable,
+    UndefinedFunction,
+    UndefinedStruct,
+    /// A struct field that does not exist or was given no value
+    UnknownField,
+    IndexOutOfBounds,
+    /// A function or struct was defined twice
+    DuplicateDefinition,
+    ArgumentCountMismatch,
+    /// A function used as a value ended without `return`ing one
+    MissingReturnValue,
+    /// Calls nested deeper than the interpreter allows
+    CallDepthExceeded,
+    /// A `döngü` ran more iterations than the interpreter allows
+    LoopLimitExceeded,
+    /// An `için` range with `adım 0`
+    ZeroStep,
+}
+
+/// Another place in the source that explains an error, such as the first
+/// definition of a duplicated name
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub location: Location,
+    pub message: String,
+}
+
+/// An Otağ function call that was active when a runtime error happened
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallFrame {
+    pub function: String,
+    /// Where the function was called
+    pub call_site: Location,
+}
+
+/// How many lines of a call-stack trace are shown before the rest is
+/// summarized
+const MAX_TRACE_LINES: usize = 10;
+
 #[derive(Debug, Clone)]
 pub struct OtagError {
     #[allow(dead_code)]
     pub error_type: ErrorType,
     pub message: String,
-    pub location: Location,
+    /// Boxed so that results carrying an error stay small
+    pub location: Box<Location>,
     pub suggestions: Vec<String>,
+    pub labels: Vec<Label>,
+    /// Function calls the error passed through, innermost first
+    pub call_stack: Vec<CallFrame>,
 }
 
 impl OtagError {
@@ -21,8 +79,10 @@ impl OtagError {
         Self {
             error_type,
             message,
-            location,
+            location: Box::new(location),
             suggestions: Vec::new(),
+            labels: Vec::new(),
+            call_stack: Vec::new(),
         }
     }
 
@@ -31,6 +91,61 @@ impl OtagError {
         self
     }
 
+    pub fn with_label(mut self, location: &Location, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            location: location.clone(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Place an error that has no position in the source, such as one about a
+    /// whole file or about two values being combined, at `location`
+    pub fn or_at(mut self, location: &Location) -> Self {
+        if self.location.line == 0 {
+            self.location = Box::new(location.clone());
+        }
+        self
+    }
+
+    /// Record that the error left a call to `function` made at `call_site`
+    pub fn in_call(mut self, function: &str, call_site: &Location) -> Self {
+        self.call_stack.push(CallFrame {
+            function: function.to_string(),
+            call_site: call_site.clone(),
+        });
+        self
+    }
+
+    /// The call stack as Turkish sentences, innermost call first. Repeated
+    /// calls from the same place, as in deep recursion, share one line.
+    pub fn trace_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut frames = self.call_stack.iter().peekable();
+        let mut shown = 0;
+        while let Some(frame) = frames.next() {
+            let mut repeats = 0;
+            while frames.next_if_eq(&frame).is_some() {
+                repeats += 1;
+            }
+            if shown == MAX_TRACE_LINES {
+                let rest = frames.len() + repeats + 1;
+                lines.push(format!("... ve {} çağrı daha", rest));
+                break;
+            }
+            let mut line = format!(
+                "fonksiyon {}, {} konumunda çağrıldı",
+                frame.function, frame.call_site
+            );
+            if repeats > 0 {
+                line.push_str(&format!(" (aynı yerden {} kez daha)", repeats));
+            }
+            lines.push(line);
+            shown += 1;
+        }
+        lines
+    }
+
     pub fn syntax(message: String, location: Location) -> Self {
         Self::new(ErrorType::Syntax, message, location)
     }
@@ -39,8 +154,12 @@ impl OtagError {
         Self::new(ErrorType::Semantic, message, location)
     }
 
-    pub fn runtime(message: String, location: Location) -> Self {
-        Self::new(ErrorType::Runtime, message, location)
+    pub fn runtime(kind: RuntimeErrorKind, message: String, location: Location) -> Self {
+        Self::new(ErrorType::Runtime(kind), message, location)
+    }
+
+    pub fn warning(message: String, location: Location) -> Self {
+        Self::new(ErrorType::Warning, message, location)
     }
 
     #[allow(dead_code)]
@@ -59,9 +178,21 @@ impl OtagError {
         Self::semantic(format!("Tanımlanmamış değişken: {}", name), location)
     }
 
-    #[allow(dead_code)]
     pub fn division_by_zero(location: Location) -> Self {
-        Self::runtime("Sıfıra bölme hatası".to_string(), location)
+        Self::runtime(
+            RuntimeErrorKind::DivisionByZero,
+            "Sıfıra bölme hatası".to_string(),
+            location,
+        )
+    }
+
+    /// The kind of a runtime error; `None` for other errors
+    #[allow(dead_code)]
+    pub fn runtime_kind(&self) -> Option<RuntimeErrorKind> {
+        match self.error_type {
+            ErrorType::Runtime(kind) => Some(kind),
+            _ => None,
+        }
     }
 }
 
@@ -74,6 +205,9 @@ impl std::fmt::Display for OtagError {
                 write!(f, "\n  - {}", suggestion)?;
             }
         }
+        for line in self.trace_lines() {
+            write!(f, "\n  {}", line)?;
+        }
         Ok(())
     }
 }
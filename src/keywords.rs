@@ -0,0 +1,164 @@
+//! Reserved words of the language.
+//!
+//! The grammar's `keyword` rule and the lexer's keyword tokens list exactly
+//! the words in [`KEYWORDS`]; the tests below keep the three in step.
+
+use crate::error_reporting::OtagError;
+use crate::location::Location;
+
+/// A reserved word, what it does, and a name to offer instead of it
+pub struct Keyword {
+    pub word: &'static str,
+    pub role: &'static str,
+    pub alternative: &'static str,
+}
+
+const fn keyword(word: &'static str, role: &'static str, alternative: &'static str) -> Keyword {
+    Keyword {
+        word,
+        role,
+        alternative,
+    }
+}
+
+pub const KEYWORDS: &[Keyword] = &[
+    keyword("kullan", "başka bir dosyayı içe aktarır", "kullanım"),
+    keyword("tanımla", "değişken tanımlar", "tanım"),
+    keyword("olarak", "tanımlanan değişkenin türünü belirtir", "biçim"),
+    keyword(
+        "değişken",
+        "türü değerinden çıkarılan değişken tanımlar",
+        "değer",
+    ),
+    keyword("söyle", "ekrana yazdırır", "mesaj"),
+    keyword("eğer", "koşul başlatır", "koşul"),
+    keyword("ise", "koşulun ya da döngünün başlığını bitirir", "durum"),
+    keyword(
+        "yoksa",
+        "koşul sağlanmadığında çalışacak bloğu başlatır",
+        "diğer",
+    ),
+    keyword("son", "bloğu kapatır", "sonuncu"),
+    keyword("döngü", "koşullu döngü başlatır", "tekrar"),
+    keyword("için", "aralık ya da dizi üzerinde döngü başlatır", "amaç"),
+    keyword(
+        "in",
+        "döngü değişkenini aralığa ya da diziye bağlar",
+        "giriş",
+    ),
+    keyword(
+        "dan",
+        "aralığın başlangıcını bitişinden ayırır",
+        "başlangıç",
+    ),
+    keyword("adım", "aralığın artış miktarını belirtir", "artış"),
+    keyword("durdur", "döngüden çıkar", "durak"),
+    keyword("devam", "döngünün sonraki adımına geçer", "sürdür"),
+    keyword("fonksiyon", "fonksiyon tanımlar", "işlev"),
+    keyword("return", "fonksiyondan değer döndürür", "dönüş"),
+    keyword("ve", "iki koşulun birlikte doğru olmasını ister", "ikisi"),
+    keyword("veya", "iki koşuldan birinin doğru olmasını ister", "biri"),
+    keyword("değil", "koşulu tersine çevirir", "tersi"),
+    keyword("doğru", "mantıksal doğru değeridir", "geçerli"),
+    keyword("yanlış", "mantıksal yanlış değeridir", "hatalı"),
+    keyword("tamsayı", "tamsayı türünün adıdır", "sayı"),
+    keyword("ondalıklı", "ondalıklı sayı türünün adıdır", "oran"),
+    keyword("metin", "metin türünün adıdır", "yazı"),
+    keyword("mantıksal", "mantıksal türün adıdır", "bayrak"),
+    keyword("dizisi", "dizi türü oluşturur", "liste"),
+];
+
+/// The reserved word spelled exactly as `word`, if there is one
+pub fn lookup(word: &str) -> Option<&'static Keyword> {
+    KEYWORDS.iter().find(|keyword| keyword.word == word)
+}
+
+/// The error for a reserved word written where a new name was expected
+pub fn reserved_name_error(word: &str, location: Location) -> OtagError {
+    let message = format!("'{}' ayrılmış bir kelimedir, ad olarak kullanılamaz", word);
+    match lookup(word) {
+        Some(keyword) => OtagError::syntax(message, location).with_suggestions(vec![
+            format!("'{}' {}", keyword.word, keyword.role),
+            format!("Başka bir ad seçin, örneğin: {}", keyword.alternative),
+        ]),
+        None => OtagError::syntax(message, location),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::{lex, Token};
+    use crate::parser::{OtagParser, Rule};
+    use pest::Parser;
+    use std::collections::BTreeSet;
+
+    /// The quoted words of the grammar's `keyword` rule
+    fn grammar_keywords() -> BTreeSet<String> {
+        let grammar = include_str!("grammar.pest");
+        let rule = grammar
+            .split("\nkeyword = ")
+            .nth(1)
+            .and_then(|rest| rest.split('}').next())
+            .expect("grammar has a `keyword` rule");
+        rule.split('"')
+            .skip(1)
+            .step_by(2)
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_grammar_reserves_exactly_the_table() {
+        let table: BTreeSet<String> = KEYWORDS.iter().map(|k| k.word.to_string()).collect();
+        assert_eq!(table.len(), KEYWORDS.len(), "duplicate keyword in table");
+        assert_eq!(grammar_keywords(), table);
+    }
+
+    #[test]
+    fn test_keywords_are_not_identifiers() {
+        for keyword in KEYWORDS {
+            assert!(
+                OtagParser::parse(Rule::keyword, keyword.word).is_ok(),
+                "{}",
+                keyword.word
+            );
+            assert!(
+                OtagParser::parse(Rule::identifier, keyword.word).is_err(),
+                "{}",
+                keyword.word
+            );
+        }
+    }
+
+    #[test]
+    fn test_lexer_has_a_token_for_every_keyword() {
+        for keyword in KEYWORDS {
+            let tokens = lex(keyword.word).unwrap();
+            assert_eq!(tokens.len(), 1, "{}", keyword.word);
+            assert_ne!(tokens[0].0, Token::Identifier, "{}", keyword.word);
+        }
+    }
+
+    #[test]
+    fn test_words_starting_with_a_keyword_are_identifiers() {
+        for word in ["sonuç", "isim", "ince", "vergi", "doğrular", "metinler"] {
+            assert!(
+                OtagParser::parse(Rule::identifier, word).is_ok(),
+                "{}",
+                word
+            );
+            assert_eq!(lex(word).unwrap()[0].0, Token::Identifier, "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_alternatives_are_valid_names() {
+        for keyword in KEYWORDS {
+            let alternative = keyword.alternative;
+            assert!(lookup(alternative).is_none(), "{}", alternative);
+            let parsed = OtagParser::parse(Rule::identifier, alternative).unwrap();
+            assert_eq!(parsed.as_str(), alternative);
+        }
+    }
+}
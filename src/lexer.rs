@@ -9,6 +9,15 @@ pub enum Token {
     #[token("tanımla")]
     Tanimla,
 
+    #[token("olarak")]
+    Olarak,
+
+    #[token("değişken")]
+    Degisken,
+
+    #[token("kullan")]
+    Kullan,
+
     #[token("söyle")]
     Soyle,
 
@@ -30,12 +39,15 @@ pub enum Token {
     #[token("=")]
     Assign,
 
-    #[regex(r"[a-zğüşöçı][a-z0-9ğüşöçı_]*")]
+    #[regex(r"\p{XID_Start}\p{XID_Continue}*")]
     Identifier,
 
-    #[regex(r#""([^"\\]|\\.)*""#)]
+    #[regex(r#""([^"\\\n]|\\.)*""#)]
     StringLiteral,
 
+    #[regex(r#"r"[^"\n]*""#)]
+    RawStringLiteral,
+
     #[regex(r"\d+\.\d+")]
     FloatLiteral,
 
@@ -66,6 +78,9 @@ pub enum Token {
     #[token("devam")]
     Devam,
 
+    #[token("in")]
+    In,
+
     #[token("ise")]
     Ise,
 
@@ -78,6 +93,18 @@ pub enum Token {
     #[token("adım")]
     Adim,
 
+    #[token("dizisi")]
+    Dizisi,
+
+    #[token("ve")]
+    Ve,
+
+    #[token("veya")]
+    Veya,
+
+    #[token("değil")]
+    Degil,
+
     #[token("fonksiyon")]
     Fonksiyon,
 
@@ -2,16 +2,20 @@
 
 pub mod ast;
 pub mod codegen;
+pub mod diagnostics;
 pub mod error_reporting;
+pub mod keywords;
 pub mod lexer;
 pub mod location;
 pub mod parser;
 pub mod semantic;
 pub mod symbol_table;
+pub mod turkish;
 pub mod types;
 
 use ast::Program;
-use error_reporting::{OtagError, Result};
+use diagnostics::{Diagnostics, SourceMap};
+use error_reporting::{OtagError, Result, RuntimeErrorKind};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -53,6 +57,8 @@ impl Default for VirtualFileSystem {
 /// Otağ runtime environment for executing programs
 pub struct OtagRuntime {
     vfs: VirtualFileSystem,
+    /// Text of every file loaded so far, for rendering diagnostics
+    sources: SourceMap,
 }
 
 impl OtagRuntime {
@@ -60,6 +66,7 @@ impl OtagRuntime {
     pub fn new() -> Self {
         Self {
             vfs: VirtualFileSystem::new(),
+            sources: SourceMap::new(),
         }
     }
 
@@ -71,29 +78,49 @@ impl OtagRuntime {
     /// Execute a program from an in-memory source file
     pub fn execute(&mut self, entry_file: &str) -> Result<()> {
         let program = self.load_program_with_imports(entry_file)?;
+        run_program(&program, &mut codegen::Interpreter::new())
+    }
 
-        // Semantic analysis
-        let mut analyzer = semantic::SemanticAnalyzer::new();
-        analyzer.analyze_program(&program)?;
-
-        // Execute
+    /// Execute a program from an in-memory source file and return the lines
+    /// it printed with `söyle` instead of writing them to stdout
+    pub fn execute_captured(&mut self, entry_file: &str) -> Result<Vec<String>> {
+        let program = self.load_program_with_imports(entry_file)?;
         let mut interpreter = codegen::Interpreter::new();
-        interpreter
-            .execute_program(&program)
-            .map_err(|e| OtagError::runtime(e, location::Location::unknown()))?;
+        interpreter.capture_output();
+        run_program(&program, &mut interpreter)?;
+        Ok(interpreter.take_output())
+    }
+
+    /// Check a program from an in-memory source file without running it and
+    /// return every error and warning found. A file that cannot be loaded or
+    /// parsed gives a single diagnostic.
+    pub fn diagnose(&mut self, entry_file: &str) -> Diagnostics {
+        match self.load_program_with_imports(entry_file) {
+            Ok(program) => analyze(&program),
+            Err(error) => Diagnostics::new(vec![error]),
+        }
+    }
+
+    /// Render an error from a program run by this runtime with the source
+    /// lines it points at, using ANSI colours when `color` is set
+    pub fn render_error(&self, error: &OtagError, color: bool) -> String {
+        diagnostics::render(error, &self.sources, color)
+    }
 
-        Ok(())
+    /// Render the result of `diagnose` followed by its summary line
+    pub fn render_diagnostics(&self, diagnostics: &Diagnostics, color: bool) -> String {
+        diagnostics.render(&self.sources, color)
     }
 
     /// Parse and load a program with all its imports from virtual file system
-    fn load_program_with_imports(&self, file_path: &str) -> Result<Program> {
+    fn load_program_with_imports(&mut self, file_path: &str) -> Result<Program> {
         let mut loaded_files = std::collections::HashSet::new();
         self.load_program_recursive(file_path, &mut loaded_files)
     }
 
     /// Recursively load program with imports from virtual file system
     fn load_program_recursive(
-        &self,
+        &mut self,
         file_path: &str,
         loaded_files: &mut std::collections::HashSet<String>,
     ) -> Result<Program> {
@@ -111,10 +138,12 @@ impl OtagRuntime {
         // Read from virtual file system
         let input = self.vfs.get_file(file_path).ok_or_else(|| {
             OtagError::runtime(
+                RuntimeErrorKind::ImportFailed,
                 format!("Dosya bulunamadı: {}", file_path),
                 location::Location::new(file_path.to_string(), 0, 0),
             )
         })?;
+        self.sources.add(file_path, input);
 
         let program = parser::parse(input, file_path)?;
 
@@ -128,14 +157,16 @@ impl OtagRuntime {
                 let import_path = base_dir.join(&import_stmt.path);
                 let import_path_str = import_path.to_str().ok_or_else(|| {
                     OtagError::runtime(
+                        RuntimeErrorKind::ImportFailed,
                         format!("Geçersiz içe aktarma yolu: {}", import_stmt.path),
-                        location::Location::unknown(),
+                        import_stmt.location.clone(),
                     )
                 })?;
 
                 // Recursively load the imported module
-                let imported_program =
-                    self.load_program_recursive(import_path_str, loaded_files)?;
+                let imported_program = self
+                    .load_program_recursive(import_path_str, loaded_files)
+                    .map_err(|error| error.or_at(&import_stmt.location))?;
 
                 // Add all statements from the imported program
                 all_statements.extend(imported_program.statements);
@@ -153,21 +184,77 @@ impl OtagRuntime {
     /// Execute a single in-memory program without imports
     pub fn execute_inline(source: &str) -> Result<()> {
         let program = parser::parse(source, "<inline>")?;
+        run_program(&program, &mut codegen::Interpreter::new())
+    }
 
-        // Semantic analysis
+    /// Check a single in-memory program without running it and return the
+    /// warnings found
+    pub fn analyze_inline(source: &str) -> Result<Vec<OtagError>> {
+        let program = parser::parse(source, "<inline>")?;
         let mut analyzer = semantic::SemanticAnalyzer::new();
         analyzer.analyze_program(&program)?;
+        Ok(analyzer.warnings().to_vec())
+    }
 
-        // Execute
-        let mut interpreter = codegen::Interpreter::new();
-        interpreter
-            .execute_program(&program)
-            .map_err(|e| OtagError::runtime(e, location::Location::unknown()))?;
+    /// Check a single in-memory program without running it and return every
+    /// error and warning found
+    pub fn diagnose_inline(source: &str) -> Diagnostics {
+        match parser::parse(source, "<inline>") {
+            Ok(program) => analyze(&program),
+            Err(error) => Diagnostics::new(vec![error]),
+        }
+    }
+
+    /// Render diagnostics for the source of `diagnose_inline`, using ANSI
+    /// colours when `color` is set
+    pub fn render_inline_diagnostics(
+        source: &str,
+        diagnostics: &Diagnostics,
+        color: bool,
+    ) -> String {
+        let mut sources = SourceMap::new();
+        sources.add("<inline>", source);
+        diagnostics.render(&sources, color)
+    }
+
+    /// Render an error returned by one of the `*_inline` functions for
+    /// `source`, using ANSI colours when `color` is set
+    pub fn render_inline_error(source: &str, error: &OtagError, color: bool) -> String {
+        let mut sources = SourceMap::new();
+        sources.add("<inline>", source);
+        diagnostics::render(error, &sources, color)
+    }
 
-        Ok(())
+    /// Execute a single in-memory program without imports and return the
+    /// lines it printed with `söyle`
+    pub fn execute_inline_captured(source: &str) -> Result<Vec<String>> {
+        let program = parser::parse(source, "<inline>")?;
+        let mut interpreter = codegen::Interpreter::new();
+        interpreter.capture_output();
+        run_program(&program, &mut interpreter)?;
+        Ok(interpreter.take_output())
     }
 }
 
+/// Run semantic analysis on an already loaded program and collect everything
+/// it found
+fn analyze(program: &Program) -> Diagnostics {
+    let mut analyzer = semantic::SemanticAnalyzer::new();
+    // The diagnostics include the error returned here
+    let _ = analyzer.analyze_program(program);
+    analyzer.diagnostics()
+}
+
+/// Run semantic analysis and then execute an already loaded program
+fn run_program(program: &Program, interpreter: &mut codegen::Interpreter) -> Result<()> {
+    // Semantic analysis
+    let mut analyzer = semantic::SemanticAnalyzer::new();
+    analyzer.analyze_program(program)?;
+
+    // Execute
+    interpreter.execute_program(program)
+}
+
 impl Default for OtagRuntime {
     fn default() -> Self {
         Self::new()
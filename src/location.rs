@@ -1,32 +1,49 @@
+/// A place in a source file. `line` and `column` (1-based, in characters)
+/// give where it starts; `start..end` is the byte range it covers, which is
+/// empty for a single position.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Location {
     pub file: String,
     pub line: usize,
     pub column: usize,
+    pub start: usize,
+    pub end: usize,
 }
 
 impl Location {
     pub fn new(file: String, line: usize, column: usize) -> Self {
-        Self { file, line, column }
+        Self {
+            file,
+            line,
+            column,
+            start: 0,
+            end: 0,
+        }
     }
 
     pub fn unknown() -> Self {
-        Self {
-            file: "<unknown>".to_string(),
-            line: 0,
-            column: 0,
-        }
+        Self::new("<unknown>".to_string(), 0, 0)
     }
 
+    /// Locates a single `position` of `input`. Builds a line table each
+    /// time, so code locating many positions should use a [`SourceText`].
     pub fn from_position(input: &str, position: usize, file: String) -> Self {
-        let before = &input[..position];
-        let line = before.chars().filter(|&c| c == '\n').count() + 1;
-        let column = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
-        Self { file, line, column }
+        SourceText::new(input).location(position, file)
     }
 
-    pub fn from_pest_span(input: &str, span: &pest::Span, file: String) -> Self {
-        Self::from_position(input, span.start(), file)
+    pub fn from_pest_span(input: &SourceText, span: &pest::Span, file: String) -> Self {
+        Self {
+            end: span.end(),
+            ..input.location(span.start(), file)
+        }
+    }
+
+    /// The span from the start of `self` to the end of `other`
+    pub fn to(&self, other: &Location) -> Self {
+        Self {
+            end: other.end,
+            ..self.clone()
+        }
     }
 
     #[allow(dead_code)]
@@ -41,6 +58,35 @@ impl Location {
     }
 }
 
+/// A source text with the byte offsets where its lines start, so that any
+/// position can be turned into a line and column without rescanning the text
+pub struct SourceText<'a> {
+    pub text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceText<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { text, line_starts }
+    }
+
+    pub fn location(&self, position: usize, file: String) -> Location {
+        let line = self.line_starts.partition_point(|&start| start <= position);
+        let line_start = self.line_starts[line - 1];
+        let column = self.text[line_start..position].chars().count() + 1;
+        Location {
+            file,
+            line,
+            column,
+            start: position,
+            end: position,
+        }
+    }
+}
+
 impl std::fmt::Display for Location {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}:{}:{}", self.file, self.line, self.column)
@@ -1,17 +1,23 @@
 mod ast;
 mod codegen;
+mod diagnostics;
 mod error_reporting;
+mod keywords;
 mod lexer;
 mod location;
 mod parser;
 mod semantic;
 mod symbol_table;
+mod turkish;
 mod types;
 
 use ast::{Program, Statement};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use diagnostics::SourceMap;
+use error_reporting::RuntimeErrorKind;
 use std::collections::HashSet;
 use std::fs;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
 #[derive(Parser)]
@@ -25,16 +31,45 @@ struct Args {
     /// Input Otağ source file (.otağ)
     #[arg(value_name = "FILE")]
     input_file: String,
+
+    /// When to colour error messages
+    #[arg(long = "renk", value_enum, default_value_t = ColorMode::Auto)]
+    color: ColorMode,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColorMode {
+    /// Colour when writing to a terminal and NO_COLOR is not set
+    #[value(name = "otomatik")]
+    Auto,
+    #[value(name = "her-zaman")]
+    Always,
+    #[value(name = "asla")]
+    Never,
+}
+
+impl ColorMode {
+    fn enabled(self) -> bool {
+        match self {
+            ColorMode::Auto => {
+                std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
 }
 
 /// Load a program with all its imports recursively
 fn load_program_with_imports(
     file_path: &str,
     loaded_files: &mut HashSet<PathBuf>,
+    sources: &mut SourceMap,
 ) -> Result<Program, crate::error_reporting::OtagError> {
     // Canonicalize the path to handle relative paths and prevent duplicate loads
     let abs_path = PathBuf::from(file_path).canonicalize().map_err(|e| {
         crate::error_reporting::OtagError::runtime(
+            RuntimeErrorKind::ImportFailed,
             format!("Dosya yolu çözümlenemedi: {}", e),
             crate::location::Location::new(file_path.to_string(), 0, 0),
         )
@@ -54,12 +89,15 @@ fn load_program_with_imports(
     // Read and parse the file
     let input = fs::read_to_string(&abs_path).map_err(|e| {
         crate::error_reporting::OtagError::runtime(
+            RuntimeErrorKind::ImportFailed,
             format!("Dosya okuma hatası: {}", e),
             crate::location::Location::new(file_path.to_string(), 0, 0),
         )
     })?;
 
-    let program = parser::parse(&input, file_path)?;
+    let program = parser::parse(&input, file_path);
+    sources.add(file_path, input);
+    let program = program?;
 
     // Process imports
     let mut all_statements = Vec::new();
@@ -71,13 +109,16 @@ fn load_program_with_imports(
             let import_path = base_dir.join(&import_stmt.path);
             let import_path_str = import_path.to_str().ok_or_else(|| {
                 crate::error_reporting::OtagError::runtime(
+                    RuntimeErrorKind::ImportFailed,
                     format!("Geçersiz içe aktarma yolu: {}", import_stmt.path),
-                    crate::location::Location::unknown(),
+                    import_stmt.location.clone(),
                 )
             })?;
 
             // Recursively load the imported module
-            let imported_program = load_program_with_imports(import_path_str, loaded_files)?;
+            let imported_program =
+                load_program_with_imports(import_path_str, loaded_files, sources)
+                    .map_err(|error| error.or_at(&import_stmt.location))?;
 
             // Add all statements from the imported program
             all_statements.extend(imported_program.statements);
@@ -92,22 +133,42 @@ fn load_program_with_imports(
     })
 }
 
-fn main() -> Result<(), crate::error_reporting::OtagError> {
+fn main() {
     let args = Args::parse();
+    let color = args.color.enabled();
 
+    let mut sources = SourceMap::new();
+    if let Err(error) = run(&args.input_file, &mut sources, color) {
+        eprintln!("{}", diagnostics::render(&error, &sources, color));
+        std::process::exit(1);
+    }
+}
+
+fn run(
+    input_file: &str,
+    sources: &mut SourceMap,
+    color: bool,
+) -> Result<(), crate::error_reporting::OtagError> {
     // Load program with all imports
     let mut loaded_files = HashSet::new();
-    let program = load_program_with_imports(&args.input_file, &mut loaded_files)?;
+    let program = load_program_with_imports(input_file, &mut loaded_files, sources)?;
 
-    // Semantic analysis
+    // Semantic analysis reports every problem it finds before giving up
     let mut analyzer = semantic::SemanticAnalyzer::new();
-    analyzer.analyze_program(&program)?;
+    // The error returned here is the first of the diagnostics
+    let _ = analyzer.analyze_program(&program);
+    let diagnostics = analyzer.diagnostics();
+    if diagnostics.has_errors() {
+        eprintln!("{}", diagnostics.render(sources, color));
+        std::process::exit(1);
+    }
+    if !diagnostics.is_empty() {
+        eprintln!("{}\n", diagnostics.render(sources, color));
+    }
 
     // Execute
     let mut interpreter = codegen::Interpreter::new();
-    interpreter.execute_program(&program).map_err(|e| {
-        crate::error_reporting::OtagError::runtime(e, crate::location::Location::unknown())
-    })?;
+    interpreter.execute_program(&program)?;
 
     Ok(())
 }
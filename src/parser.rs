@@ -1,10 +1,14 @@
 use crate::ast::*;
 use crate::error_reporting::*;
+use crate::keywords;
 use crate::location::*;
 use crate::types::*;
+use pest::pratt_parser::{Assoc, Op, PrattParser};
 use pest::Parser;
 use pest_derive::Parser;
 use std::collections::HashSet;
+use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
 
 #[derive(Parser)]
 #[grammar = "src/grammar.pest"]
@@ -15,190 +19,575 @@ type RangeSpecResult = Result<(Box<Expression>, Box<Expression>, Option<Box<Expr
 
 pub fn parse(input: &str, file: &str) -> Result<Program> {
     let mut pairs = OtagParser::parse(Rule::program, input).map_err(|e| {
-        // Try to extract location from pest error
-        let location = match e.location {
-            pest::error::InputLocation::Pos(pos) => {
-                Location::from_position(input, pos, file.to_string())
-            }
-            pest::error::InputLocation::Span((start, _)) => {
-                Location::from_position(input, start, file.to_string())
-            }
+        let position = match e.location {
+            pest::error::InputLocation::Pos(pos) => pos,
+            pest::error::InputLocation::Span((start, _)) => start,
         };
-        OtagError::syntax(format!("Parse hatası: {}", e), location)
+        let location = Location::from_position(input, position, file.to_string());
+        OtagError::syntax(
+            syntax_error_message(&e.variant, &input[position..]),
+            location,
+        )
     })?;
 
     let program_pair = pairs.next().unwrap();
+    let input = SourceText::new(input);
     let mut statements = Vec::new();
 
     for inner in program_pair.into_inner() {
         if inner.as_rule() == Rule::statement {
-            statements.push(parse_statement(input, file, inner)?);
+            statements.push(parse_statement(&input, file, inner)?);
         }
     }
 
     Ok(Program { statements })
 }
 
+/// Turkish wording for a pest error, naming what could come next instead of
+/// the grammar's rules. `rest` is the input from the error onwards.
+fn syntax_error_message(variant: &pest::error::ErrorVariant<Rule>, rest: &str) -> String {
+    let found = match rest.split(char::is_whitespace).next() {
+        _ if rest.is_empty() => "dosya sonu".to_string(),
+        Some("") => "satır sonu".to_string(),
+        Some(word) => format!("'{}'", word.chars().take(20).collect::<String>()),
+        None => unreachable!("split always yields a first piece"),
+    };
+    let positives = match variant {
+        pest::error::ErrorVariant::ParsingError { positives, .. } => positives,
+        pest::error::ErrorVariant::CustomError { message } => return message.clone(),
+    };
+    let mut expected: Vec<&str> = Vec::new();
+    for description in positives.iter().map(describe_rule) {
+        if !expected.contains(&description) {
+            expected.push(description);
+        }
+    }
+    match expected.split_last() {
+        None => format!("Beklenmeyen {}", found),
+        Some((last, [])) => format!("{} bekleniyordu, bulunan: {}", capitalize(last), found),
+        Some((last, rest)) => format!(
+            "{} ya da {} bekleniyordu, bulunan: {}",
+            capitalize(&rest.join(", ")),
+            last,
+            found
+        ),
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// What a rule reads, for syntax errors
+fn describe_rule(rule: &Rule) -> &'static str {
+    match rule {
+        Rule::EOI => "dosya sonu",
+        Rule::program | Rule::statement | Rule::control_block => "bir komut",
+        Rule::expression
+        | Rule::term
+        | Rule::primary
+        | Rule::prefix_operator
+        | Rule::condition
+        | Rule::argument_list
+        | Rule::negate
+        | Rule::not
+        | Rule::literal
+        | Rule::array_literal
+        | Rule::struct_literal
+        | Rule::int_literal
+        | Rule::float_literal
+        | Rule::boolean_literal => "bir değer",
+        Rule::string_literal
+        | Rule::quoted_string
+        | Rule::multiline_string
+        | Rule::raw_string
+        | Rule::escape => "bir metin",
+        Rule::identifier
+        | Rule::identifier_char
+        | Rule::name
+        | Rule::reserved_name
+        | Rule::keyword => "bir ad",
+        Rule::possessive_suffix => "'ı gibi bir ek",
+        Rule::field_accessor => "'.'",
+        Rule::index_accessor => "'['",
+        Rule::call_arguments => "'('",
+        Rule::accessor => "'.' ya da '['",
+        Rule::WHITESPACE => "boşluk",
+        Rule::operator
+        | Rule::power
+        | Rule::add
+        | Rule::subtract
+        | Rule::multiply
+        | Rule::divide
+        | Rule::modulo
+        | Rule::less_equal
+        | Rule::less
+        | Rule::greater_equal
+        | Rule::greater
+        | Rule::equal
+        | Rule::not_equal
+        | Rule::and
+        | Rule::or => "bir işleç",
+        Rule::type_keyword | Rule::scalar_type => "bir tür",
+        Rule::array_suffix => "'dizisi'",
+        Rule::field_definition => "bir alan (ad: tür)",
+        Rule::field_init => "bir alan değeri (ad: değer)",
+        Rule::parameter_list | Rule::parameter => "bir parametre (ad: tür)",
+        Rule::return_part => "'->'",
+        Rule::range_spec => "bir aralık (1 dan 10)",
+        Rule::declaration_keyword => "'değişken'",
+        Rule::import_statement
+        | Rule::struct_definition
+        | Rule::variable_declaration
+        | Rule::inferred_declaration
+        | Rule::assignment
+        | Rule::output_statement
+        | Rule::if_statement
+        | Rule::while_statement
+        | Rule::for_statement
+        | Rule::for_each_statement
+        | Rule::break_statement
+        | Rule::continue_statement
+        | Rule::return_statement
+        | Rule::returns_value
+        | Rule::function_definition
+        | Rule::expression_statement => "bir komut",
+    }
+}
+
 fn parse_statement(
-    input: &str,
+    input: &SourceText,
     file: &str,
     pair: pest::iterators::Pair<Rule>,
 ) -> Result<Statement> {
     let inner = pair.into_inner().next().unwrap();
 
     match inner.as_rule() {
-        Rule::import_statement => Ok(Statement::Import(parse_import_statement(inner)?)),
+        Rule::import_statement => Ok(Statement::Import(parse_import_statement(
+            inner, input, file,
+        )?)),
         Rule::variable_declaration => Ok(Statement::VariableDeclaration(
             parse_variable_declaration(inner, input, file)?,
         )),
-        Rule::assignment => Ok(Statement::Assignment(parse_assignment(inner)?)),
-        Rule::output_statement => Ok(Statement::Output(parse_output_statement(inner)?)),
+        Rule::inferred_declaration => Ok(Statement::VariableDeclaration(
+            parse_inferred_declaration(inner, input, file)?,
+        )),
+        Rule::assignment => Ok(Statement::Assignment(parse_assignment(inner, input, file)?)),
+        Rule::output_statement => Ok(Statement::Output(parse_output_statement(
+            inner, input, file,
+        )?)),
         Rule::if_statement => Ok(Statement::If(parse_if_statement(input, file, inner)?)),
         Rule::while_statement => Ok(Statement::WhileLoop(parse_while_statement(
             input, file, inner,
         )?)),
         Rule::for_statement => Ok(Statement::ForLoop(parse_for_statement(input, file, inner)?)),
-        Rule::break_statement => Ok(Statement::Break),
-        Rule::continue_statement => Ok(Statement::Continue),
+        Rule::for_each_statement => Ok(Statement::ForEachLoop(parse_for_each_statement(
+            input, file, inner,
+        )?)),
+        Rule::break_statement => Ok(Statement::Break(Location::from_pest_span(
+            input,
+            &inner.as_span(),
+            file.to_string(),
+        ))),
+        Rule::continue_statement => Ok(Statement::Continue(Location::from_pest_span(
+            input,
+            &inner.as_span(),
+            file.to_string(),
+        ))),
         Rule::function_definition => Ok(Statement::FunctionDefinition(parse_function_definition(
             input, file, inner,
         )?)),
-        Rule::return_statement => Ok(Statement::Return(parse_return_statement(inner)?)),
+        Rule::return_statement => Ok(Statement::Return(parse_return_statement(
+            inner, input, file,
+        )?)),
         Rule::struct_definition => Ok(Statement::StructDefinition(parse_struct_definition(
             inner, input, file,
         )?)),
+        // Shaped like a term that ends in a call
+        Rule::expression_statement => Ok(Statement::Expression(parse_term(inner, input, file)?)),
         _ => Err(OtagError::syntax(
             format!("Bilinmeyen ifade türü: {:?}", inner.as_rule()),
-            Location::unknown(),
+            Location::from_pest_span(input, &inner.as_span(), file.to_string()),
         )),
     }
 }
 
-fn parse_import_statement(pair: pest::iterators::Pair<Rule>) -> Result<ImportStatement> {
+fn parse_import_statement(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<ImportStatement> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
-    let string_literal = inner.next().unwrap();
-    let s = string_literal.as_str();
-    // Remove quotes
-    let path = s[1..s.len() - 1].to_string();
+    let path = parse_string_literal(inner.next().unwrap(), input, file)?;
 
-    Ok(ImportStatement { path })
+    Ok(ImportStatement { path, location })
 }
 
 fn parse_variable_declaration(
     pair: pest::iterators::Pair<Rule>,
-    input: &str,
+    input: &SourceText,
     file: &str,
 ) -> Result<VariableDeclaration> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
-    // "'ı" is matched but not captured
+    let name = declared_name(inner.next().unwrap(), input, file)?;
+    let suffix_pair = inner.next().unwrap();
+    let suffix = Suffix {
+        text: suffix_pair.as_str().to_string(),
+        location: Location::from_pest_span(input, &suffix_pair.as_span(), file.to_string()),
+    };
     let type_pair = inner.next().unwrap();
 
-    let var_type = parse_type_keyword(type_pair, input, file)?;
+    let var_type = parse_type_keyword(type_pair);
+    let initializer = inner
+        .next()
+        .map(|expr| parse_expression(expr, input, file))
+        .transpose()?;
 
-    Ok(VariableDeclaration { name, var_type })
+    Ok(VariableDeclaration {
+        name,
+        suffix: Some(suffix),
+        var_type: Some(var_type),
+        initializer,
+        location,
+    })
 }
 
-fn parse_assignment(pair: pest::iterators::Pair<Rule>) -> Result<Assignment> {
+fn parse_inferred_declaration(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<VariableDeclaration> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
-    let expr = parse_expression(inner.next().unwrap())?;
+    // Skip "değişken"
+    inner.next();
+    let name = declared_name(inner.next().unwrap(), input, file)?;
+    let initializer = parse_expression(inner.next().unwrap(), input, file)?;
+
+    Ok(VariableDeclaration {
+        name,
+        suffix: None,
+        var_type: None,
+        initializer: Some(initializer),
+        location,
+    })
+}
+
+fn parse_assignment(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<Assignment> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
+    let mut inner = pair.into_inner();
+    let name = declared_name(inner.next().unwrap(), input, file)?;
+    let mut accessors = Vec::new();
+    let mut expr = None;
+    for part in inner {
+        match part.as_rule() {
+            Rule::field_accessor => {
+                let field = identifier_name(part.into_inner().next().unwrap());
+                accessors.push(Accessor::Field(field));
+            }
+            Rule::index_accessor => {
+                let index = parse_expression(part.into_inner().next().unwrap(), input, file)?;
+                accessors.push(Accessor::Index(index));
+            }
+            _ => expr = Some(parse_expression(part, input, file)?),
+        }
+    }
 
     Ok(Assignment {
         name,
+        accessors,
+        expression: expr.unwrap(),
+        location,
+    })
+}
+
+fn parse_output_statement(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<OutputStatement> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
+    let expr = parse_expression(pair.into_inner().next().unwrap(), input, file)?;
+
+    Ok(OutputStatement {
         expression: expr,
+        location,
     })
 }
 
-fn parse_output_statement(pair: pest::iterators::Pair<Rule>) -> Result<OutputStatement> {
-    let expr = parse_expression(pair.into_inner().next().unwrap())?;
+/// Operator precedence table, from loosest to tightest binding
+fn pratt_parser() -> &'static PrattParser<Rule> {
+    static PRATT_PARSER: OnceLock<PrattParser<Rule>> = OnceLock::new();
+    PRATT_PARSER.get_or_init(|| {
+        PrattParser::new()
+            .op(Op::infix(Rule::or, Assoc::Left))
+            .op(Op::infix(Rule::and, Assoc::Left))
+            .op(Op::prefix(Rule::not))
+            .op(Op::infix(Rule::equal, Assoc::Left) | Op::infix(Rule::not_equal, Assoc::Left))
+            .op(Op::infix(Rule::less, Assoc::Left)
+                | Op::infix(Rule::less_equal, Assoc::Left)
+                | Op::infix(Rule::greater, Assoc::Left)
+                | Op::infix(Rule::greater_equal, Assoc::Left))
+            .op(Op::infix(Rule::add, Assoc::Left) | Op::infix(Rule::subtract, Assoc::Left))
+            .op(Op::infix(Rule::multiply, Assoc::Left)
+                | Op::infix(Rule::divide, Assoc::Left)
+                | Op::infix(Rule::modulo, Assoc::Left))
+            .op(Op::prefix(Rule::negate))
+            .op(Op::infix(Rule::power, Assoc::Right))
+    })
+}
 
-    Ok(OutputStatement { expression: expr })
+fn parse_expression(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<Expression> {
+    pratt_parser()
+        .map_primary(|primary| parse_operand(primary, input, file))
+        .map_infix(|left, op, right| {
+            let (left, right) = (left?.into_expression()?, right?.into_expression()?);
+            let location = left.location.to(&right.location);
+            let op = parse_binary_operator(op, input, file)?;
+            Ok(Operand::Expression(Expression::new(
+                ExpressionKind::BinaryOp(Box::new(left), op, Box::new(right)),
+                location,
+            )))
+        })
+        .map_prefix(|op, operand| {
+            let op_location = Location::from_pest_span(input, &op.as_span(), file.to_string());
+            let op = parse_unary_operator(op, input, file)?;
+            let operand = match operand? {
+                // A minus sign directly in front of a number is a negative literal
+                Operand::Integer(value, location) if matches!(op, UnaryOperator::Negate) => {
+                    return Ok(Operand::Integer(-value, op_location.to(&location)));
+                }
+                operand => operand.into_expression()?,
+            };
+            let location = op_location.to(&operand.location);
+            let kind = match (op, operand.kind) {
+                (UnaryOperator::Negate, ExpressionKind::Literal(VariableValue::Float(f))) => {
+                    ExpressionKind::Literal(VariableValue::Float(-f))
+                }
+                (op, kind) => {
+                    ExpressionKind::UnaryOp(op, Box::new(Expression::new(kind, operand.location)))
+                }
+            };
+            Ok(Operand::Expression(Expression::new(kind, location)))
+        })
+        .parse(pair.into_inner())?
+        .into_expression()
 }
 
-fn parse_expression(pair: pest::iterators::Pair<Rule>) -> Result<Expression> {
-    let mut terms = Vec::new();
-    let mut ops = Vec::new();
+/// Operand of an expression being parsed. An integer literal keeps its
+/// magnitude until a leading `-` is folded in, so `-2147483648` is
+/// range-checked as a whole.
+enum Operand {
+    Expression(Expression),
+    Integer(i64, Location),
+}
 
-    for inner in pair.into_inner() {
-        match inner.as_rule() {
-            Rule::term => {
-                let expr = parse_term(inner)?;
-                terms.push(expr);
-            }
-            Rule::operator => {
-                let op_str = inner.as_str();
-                ops.push(match op_str {
-                    "+" => BinaryOperator::Add,
-                    ">" => BinaryOperator::GreaterThan,
-                    ">=" => BinaryOperator::GreaterThanOrEqual,
-                    "<" => BinaryOperator::LessThan,
-                    "<=" => BinaryOperator::LessThanOrEqual,
-                    _ => {
-                        return Err(OtagError::syntax(
-                            format!("Bilinmeyen operatör: {}", op_str),
-                            Location::unknown(),
-                        ))
-                    }
-                });
-            }
-            _ => {
-                return Err(OtagError::syntax(
-                    format!("İfadede beklenmeyen token: {:?}", inner.as_rule()),
-                    Location::unknown(),
+impl Operand {
+    fn into_expression(self) -> Result<Expression> {
+        match self {
+            Operand::Expression(expr) => Ok(expr),
+            Operand::Integer(value, location) => {
+                let value = i32::try_from(value)
+                    .map_err(|_| invalid_integer(&value.to_string(), location.clone()))?;
+                Ok(Expression::new(
+                    ExpressionKind::Literal(VariableValue::Int(value)),
+                    location,
                 ))
             }
         }
     }
+}
 
-    if terms.is_empty() {
-        return Err(OtagError::syntax(
-            "İfade boş olamaz".to_string(),
-            Location::unknown(),
-        ));
+fn parse_operand(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<Operand> {
+    let mut inner = pair.clone().into_inner();
+    if let (Some(literal), None) = (inner.next(), inner.next()) {
+        if literal.as_rule() == Rule::literal {
+            let number = literal.into_inner().next().unwrap();
+            if number.as_rule() == Rule::int_literal {
+                let location = Location::from_pest_span(input, &number.as_span(), file.to_string());
+                let digits = number.as_str().trim();
+                let value = digits
+                    .parse()
+                    .map_err(|_| invalid_integer(digits, location.clone()))?;
+                return Ok(Operand::Integer(value, location));
+            }
+        }
     }
+    Ok(Operand::Expression(parse_term(pair, input, file)?))
+}
 
-    if terms.len() == 1 {
-        Ok(terms[0].clone())
-    } else {
-        // Build left-associated binary operations
-        let mut result = terms[0].clone();
-        for i in 0..ops.len() {
-            let right = terms[i + 1].clone();
-            result = Expression::BinaryOp(Box::new(result), ops[i].clone(), Box::new(right));
-        }
-        Ok(result)
+fn parse_binary_operator(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<BinaryOperator> {
+    match pair.as_rule() {
+        Rule::add => Ok(BinaryOperator::Add),
+        Rule::subtract => Ok(BinaryOperator::Subtract),
+        Rule::multiply => Ok(BinaryOperator::Multiply),
+        Rule::divide => Ok(BinaryOperator::Divide),
+        Rule::modulo => Ok(BinaryOperator::Modulo),
+        Rule::power => Ok(BinaryOperator::Power),
+        Rule::greater => Ok(BinaryOperator::GreaterThan),
+        Rule::greater_equal => Ok(BinaryOperator::GreaterThanOrEqual),
+        Rule::less => Ok(BinaryOperator::LessThan),
+        Rule::less_equal => Ok(BinaryOperator::LessThanOrEqual),
+        Rule::equal => Ok(BinaryOperator::Equal),
+        Rule::not_equal => Ok(BinaryOperator::NotEqual),
+        Rule::and => Ok(BinaryOperator::And),
+        Rule::or => Ok(BinaryOperator::Or),
+        _ => Err(OtagError::syntax(
+            format!("Bilinmeyen operatör: {}", pair.as_str()),
+            Location::from_pest_span(input, &pair.as_span(), file.to_string()),
+        )),
     }
 }
 
-fn parse_term(pair: pest::iterators::Pair<Rule>) -> Result<Expression> {
-    let inner = pair.into_inner().next().unwrap();
+fn parse_unary_operator(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<UnaryOperator> {
+    match pair.as_rule() {
+        Rule::negate => Ok(UnaryOperator::Negate),
+        Rule::not => Ok(UnaryOperator::Not),
+        _ => Err(OtagError::syntax(
+            format!("Bilinmeyen operatör: {}", pair.as_str()),
+            Location::from_pest_span(input, &pair.as_span(), file.to_string()),
+        )),
+    }
+}
 
-    match inner.as_rule() {
-        Rule::identifier => Ok(Expression::VariableRef(inner.as_str().to_string())),
-        Rule::literal => Ok(Expression::Literal(parse_literal(inner)?)),
-        Rule::function_call => Ok(Expression::FunctionCall(parse_function_call(inner)?)),
-        Rule::array_literal => Ok(Expression::ArrayLiteral(parse_array_literal(inner)?)),
-        Rule::array_access => Ok(Expression::ArrayAccess(parse_array_access(inner)?)),
-        _ => Err(OtagError::syntax(format!("Tanımlayıcı, değişmez, fonksiyon çağrısı, dizi değişmezi veya dizi erişimi bekleniyordu, bulunan: {:?}", inner.as_rule()), Location::unknown())),
+/// Postfixes apply left to right: `a[0].b` becomes `(a[0]).b` and `f(x)[0]`
+/// becomes `(f(x))[0]`
+fn parse_term(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<Expression> {
+    let span = pair.as_span();
+    let mut inner = pair.into_inner();
+    let first = inner.next().unwrap();
+    let parenthesized = first.as_rule() == Rule::expression;
+    let mut primary = parse_primary(first, input, file)?;
+    if parenthesized {
+        // Widen the span over the parentheses
+        let close = closing_paren_end(input.text, primary.location.end);
+        let span = pest::Span::new(input.text, span.start(), close).unwrap();
+        primary.location = Location::from_pest_span(input, &span, file.to_string());
+    }
+    inner.try_fold(primary, |expr, postfix| {
+        // Each access spans from the start of the term to its own end
+        let location = expr.location.to(&Location::from_pest_span(
+            input,
+            &postfix.as_span(),
+            file.to_string(),
+        ));
+        let kind = match postfix.as_rule() {
+            Rule::field_accessor => ExpressionKind::StructAccess(StructAccess {
+                struct_expr: Box::new(expr),
+                field_name: identifier_name(postfix.into_inner().next().unwrap()),
+            }),
+            Rule::call_arguments => ExpressionKind::FunctionCall(FunctionCall {
+                callee: Box::new(expr),
+                arguments: parse_arguments(postfix, input, file)?,
+                location: location.clone(),
+            }),
+            _ => ExpressionKind::ArrayAccess(ArrayAccess {
+                array: Box::new(expr),
+                index: Box::new(parse_expression(
+                    postfix.into_inner().next().unwrap(),
+                    input,
+                    file,
+                )?),
+            }),
+        };
+        Ok(Expression::new(kind, location))
+    })
+}
+
+/// The end of the `)` that follows, after whitespace and comments, an
+/// expression ending at `end`
+fn closing_paren_end(input: &str, end: usize) -> usize {
+    let mut rest = &input[end..];
+    loop {
+        rest = rest.trim_start_matches([' ', '\t', '\n', '\r']);
+        match rest.strip_prefix('#') {
+            Some(comment) => rest = comment.find('\n').map_or("", |i| &comment[i..]),
+            None => break,
+        }
     }
+    input.len() - rest.len() + ')'.len_utf8()
 }
 
-fn parse_literal(pair: pest::iterators::Pair<Rule>) -> Result<VariableValue> {
+fn parse_primary(
+    inner: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<Expression> {
+    let location = Location::from_pest_span(input, &inner.as_span(), file.to_string());
+    let kind = match inner.as_rule() {
+        Rule::identifier => ExpressionKind::VariableRef(identifier_name(inner)),
+        Rule::reserved_name => return Err(keywords::reserved_name_error(inner.as_str(), location)),
+        Rule::literal => ExpressionKind::Literal(parse_literal(inner, input, file)?),
+        Rule::array_literal => ExpressionKind::ArrayLiteral(parse_array_literal(inner, input, file)?),
+        Rule::struct_literal => ExpressionKind::StructLiteral(parse_struct_literal(
+            inner, input, file,
+        )?),
+        Rule::expression => return parse_expression(inner, input, file),
+        _ => return Err(OtagError::syntax(format!("Tanımlayıcı, değişmez, fonksiyon çağrısı, dizi değişmezi veya dizi erişimi bekleniyordu, bulunan: {:?}", inner.as_rule()), location)),
+    };
+    Ok(Expression::new(kind, location))
+}
+
+fn invalid_integer(text: &str, location: Location) -> OtagError {
+    OtagError::syntax(format!("Geçersiz tamsayı: {}", text), location).with_suggestions(vec![
+        format!(
+            "Tamsayılar {} ile {} arasında olmalıdır",
+            i32::MIN,
+            i32::MAX
+        ),
+    ])
+}
+
+fn parse_literal(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<VariableValue> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let inner = pair.into_inner().next().unwrap();
     let s = inner.as_str();
 
     match inner.as_rule() {
-        Rule::string_literal => {
-            // Remove quotes
-            let content = &s[1..s.len() - 1];
-            Ok(VariableValue::String(content.to_string()))
-        }
-        Rule::int_literal => Ok(VariableValue::Int(s.trim().parse().map_err(|_| {
-            OtagError::syntax("Geçersiz tamsayı".to_string(), Location::unknown())
-        })?)),
+        Rule::string_literal => Ok(VariableValue::String(parse_string_literal(
+            inner, input, file,
+        )?)),
+        Rule::int_literal => Ok(VariableValue::Int(
+            s.trim()
+                .parse()
+                .map_err(|_| invalid_integer(s, location.clone()))?,
+        )),
         Rule::float_literal => Ok(VariableValue::Float(s.trim().parse().map_err(|_| {
-            OtagError::syntax("Geçersiz ondalıklı sayı".to_string(), Location::unknown())
+            OtagError::syntax("Geçersiz ondalıklı sayı".to_string(), location.clone())
         })?)),
         Rule::boolean_literal => {
             let val = s.trim() == "doğru";
@@ -206,16 +595,123 @@ fn parse_literal(pair: pest::iterators::Pair<Rule>) -> Result<VariableValue> {
         }
         _ => Err(OtagError::syntax(
             format!("Bilinmeyen değişmez türü: {:?}", inner.as_rule()),
-            Location::unknown(),
+            location,
         )),
     }
 }
 
-fn parse_array_literal(pair: pest::iterators::Pair<Rule>) -> Result<ArrayLiteral> {
+fn parse_string_literal(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<String> {
+    let inner = pair.into_inner().next().unwrap();
+    let text = inner.as_str();
+    let start = inner.as_span().start();
+    match inner.as_rule() {
+        Rule::raw_string => {
+            let quotes = if text.starts_with("r\"\"\"") { 3 } else { 1 };
+            Ok(normalize_newlines(&text[1 + quotes..text.len() - quotes]))
+        }
+        Rule::multiline_string => {
+            let content = &text[3..text.len() - 3];
+            // A line break right after the opening quotes only separates
+            // them from the first line
+            let skipped = if content.starts_with("\r\n") {
+                2
+            } else if content.starts_with('\n') {
+                1
+            } else {
+                0
+            };
+            let decoded = decode_escapes(&content[skipped..], start + 3 + skipped, input, file)?;
+            Ok(normalize_newlines(&decoded))
+        }
+        _ => decode_escapes(&text[1..text.len() - 1], start + 1, input, file),
+    }
+}
+
+/// Line breaks in multi-line strings read the same whatever the file uses
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
+
+/// Decodes the escapes in a string literal's contents, which start at byte
+/// `offset` of `input`, so that a bad escape is reported where it is written
+fn decode_escapes(content: &str, offset: usize, input: &SourceText, file: &str) -> Result<String> {
+    let mut decoded = String::with_capacity(content.len());
+    let mut chars = content.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        if c != '\\' {
+            decoded.push(c);
+            continue;
+        }
+        let location = input.location(offset + position, file.to_string());
+        let Some((_, escape)) = chars.next() else {
+            return Err(invalid_escape("\\".to_string(), location));
+        };
+        match escape {
+            'n' => decoded.push('\n'),
+            't' => decoded.push('\t'),
+            'r' => decoded.push('\r'),
+            '0' => decoded.push('\0'),
+            '\\' => decoded.push('\\'),
+            '"' => decoded.push('"'),
+            '\'' => decoded.push('\''),
+            'u' => {
+                if chars.next_if(|(_, c)| *c == '{').is_none() {
+                    return Err(OtagError::syntax(
+                        "Unicode kaçışı \\u{...} biçiminde yazılmalıdır".to_string(),
+                        location,
+                    )
+                    .with_suggestions(vec!["Örneğin: \\u{00E7} (ç)".to_string()]));
+                }
+                let mut digits = String::new();
+                while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_hexdigit()) {
+                    digits.push(c);
+                }
+                let closed = chars.next_if(|(_, c)| *c == '}').is_some();
+                let character = u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|_| closed && (1..=6).contains(&digits.len()))
+                    .and_then(char::from_u32);
+                match character {
+                    Some(character) => decoded.push(character),
+                    None => {
+                        return Err(OtagError::syntax(
+                            format!("Geçersiz Unicode kaçışı: \\u{{{}}}", digits),
+                            location,
+                        )
+                        .with_suggestions(vec![
+                            "Küme parantezleri arasına 1-6 basamaklı onaltılık bir kod yazın, en fazla 10FFFF".to_string(),
+                        ]));
+                    }
+                }
+            }
+            other => return Err(invalid_escape(format!("\\{}", other), location)),
+        }
+    }
+    Ok(decoded)
+}
+
+fn invalid_escape(escape: String, location: Location) -> OtagError {
+    OtagError::syntax(format!("Geçersiz kaçış dizisi: {}", escape), location).with_suggestions(
+        vec![
+            "Geçerli kaçışlar: \\n, \\t, \\r, \\0, \\\\, \\\", \\', \\u{...}".to_string(),
+            "Ters eğik çizgiyi olduğu gibi yazmak için \\\\ ya da r\"...\" kullanın".to_string(),
+        ],
+    )
+}
+
+fn parse_array_literal(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<ArrayLiteral> {
     let mut elements = Vec::new();
     for inner in pair.into_inner() {
         if inner.as_rule() == Rule::expression {
-            elements.push(parse_expression(inner)?);
+            elements.push(parse_expression(inner, input, file)?);
         }
     }
     Ok(ArrayLiteral {
@@ -224,27 +720,44 @@ fn parse_array_literal(pair: pest::iterators::Pair<Rule>) -> Result<ArrayLiteral
     })
 }
 
-fn parse_array_access(pair: pest::iterators::Pair<Rule>) -> Result<ArrayAccess> {
+fn parse_struct_literal(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<StructLiteral> {
     let mut inner = pair.into_inner();
-    let array_name = inner.next().unwrap().as_str().to_string();
-    let index_expr = parse_expression(inner.next().unwrap())?;
-    Ok(ArrayAccess {
-        array: Box::new(Expression::VariableRef(array_name)),
-        index: Box::new(index_expr),
+    let struct_name = identifier_name(inner.next().unwrap());
+    let mut fields = Vec::new();
+    for field_pair in inner {
+        let location = Location::from_pest_span(input, &field_pair.as_span(), file.to_string());
+        let mut field_inner = field_pair.into_inner();
+        let name = identifier_name(field_inner.next().unwrap());
+        let value = parse_expression(field_inner.next().unwrap(), input, file)?;
+        fields.push(FieldAssignment {
+            name,
+            value,
+            location,
+        });
+    }
+    Ok(StructLiteral {
+        struct_name,
+        fields,
     })
 }
 
 fn parse_if_statement(
-    input: &str,
+    input: &SourceText,
     file: &str,
     pair: pest::iterators::Pair<Rule>,
 ) -> Result<IfStatement> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
     // Skip "eğer"
     let condition_pair = inner.next().unwrap();
     let expr_pair = condition_pair.into_inner().next().unwrap();
     let condition = Condition {
-        expression: Box::new(parse_expression(expr_pair)?),
+        location: Location::from_pest_span(input, &expr_pair.as_span(), file.to_string()),
+        expression: Box::new(parse_expression(expr_pair, input, file)?),
     };
     // Skip "ise"
     let then_block = parse_control_block(input, file, inner.next().unwrap())?;
@@ -258,42 +771,50 @@ fn parse_if_statement(
         condition,
         then_block,
         else_block,
+        location,
     })
 }
 
 fn parse_while_statement(
-    input: &str,
+    input: &SourceText,
     file: &str,
     pair: pest::iterators::Pair<Rule>,
 ) -> Result<WhileLoop> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
     // Skip "döngü"
     let condition_pair = inner.next().unwrap();
     let expr_pair = condition_pair.into_inner().next().unwrap();
     let condition = Condition {
-        expression: Box::new(parse_expression(expr_pair)?),
+        location: Location::from_pest_span(input, &expr_pair.as_span(), file.to_string()),
+        expression: Box::new(parse_expression(expr_pair, input, file)?),
     };
     // Skip "ise"
     let body = parse_control_block(input, file, inner.next().unwrap())?;
     // Skip "son"
-    Ok(WhileLoop { condition, body })
+    Ok(WhileLoop {
+        condition,
+        body,
+        location,
+    })
 }
 
 fn parse_for_statement(
-    input: &str,
+    input: &SourceText,
     file: &str,
     pair: pest::iterators::Pair<Rule>,
 ) -> Result<ForLoop> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
     // Skip "için"
-    let var_name = inner.next().unwrap().as_str().to_string();
+    let var_name = declared_name(inner.next().unwrap(), input, file)?;
     let loop_variable = LoopVariable {
         name: var_name,
         is_auto_generated: false,
     };
     // Skip "in"
     let range_spec = inner.next().unwrap();
-    let (range_start, range_end, step) = parse_range_spec(range_spec)?;
+    let (range_start, range_end, step) = parse_range_spec(range_spec, input, file)?;
     // Skip "ise"
     let body = parse_control_block(input, file, inner.next().unwrap())?;
     // Skip "son"
@@ -303,11 +824,42 @@ fn parse_for_statement(
         range_end,
         step,
         body,
+        location,
+    })
+}
+
+fn parse_for_each_statement(
+    input: &SourceText,
+    file: &str,
+    pair: pest::iterators::Pair<Rule>,
+) -> Result<ForEachLoop> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
+    let mut names = Vec::new();
+    let mut iterable = None;
+    let mut body = None;
+    for part in pair.into_inner() {
+        match part.as_rule() {
+            Rule::identifier | Rule::reserved_name => names.push(declared_name(part, input, file)?),
+            Rule::expression => iterable = Some(parse_expression(part, input, file)?),
+            _ => body = Some(parse_control_block(input, file, part)?),
+        }
+    }
+    // The element variable always comes last
+    let name = names.pop().unwrap();
+    Ok(ForEachLoop {
+        index_variable: names.pop(),
+        loop_variable: LoopVariable {
+            name,
+            is_auto_generated: false,
+        },
+        iterable: iterable.unwrap(),
+        body: body.unwrap(),
+        location,
     })
 }
 
 fn parse_control_block(
-    input: &str,
+    input: &SourceText,
     file: &str,
     pair: pest::iterators::Pair<Rule>,
 ) -> Result<ControlBlock> {
@@ -320,14 +872,18 @@ fn parse_control_block(
     Ok(ControlBlock { statements })
 }
 
-fn parse_range_spec(pair: pest::iterators::Pair<Rule>) -> RangeSpecResult {
+fn parse_range_spec(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> RangeSpecResult {
     let mut inner = pair.into_inner();
-    let start = Box::new(parse_expression(inner.next().unwrap())?);
+    let start = Box::new(parse_expression(inner.next().unwrap(), input, file)?);
     // Skip "dan"
-    let end = Box::new(parse_expression(inner.next().unwrap())?);
+    let end = Box::new(parse_expression(inner.next().unwrap(), input, file)?);
     let step = if let Some(step_pair) = inner.next() {
         // Skip "adım"
-        Some(Box::new(parse_expression(step_pair)?))
+        Some(Box::new(parse_expression(step_pair, input, file)?))
     } else {
         None
     };
@@ -335,21 +891,32 @@ fn parse_range_spec(pair: pest::iterators::Pair<Rule>) -> RangeSpecResult {
 }
 
 fn parse_function_definition(
-    input: &str,
+    input: &SourceText,
     file: &str,
     pair: pest::iterators::Pair<Rule>,
 ) -> Result<FunctionDefinition> {
     let span = pair.as_span();
     let mut inner = pair.into_inner();
     // Skip "fonksiyon"
-    let name = inner.next().unwrap().as_str().to_string();
-    // Skip "("
+    let name = declared_name(inner.next().unwrap(), input, file)?;
+    // Parameters, return type and body are all optional, so dispatch on the rule
     let mut parameters = Vec::new();
-    if let Some(param_list) = inner.next() {
-        if param_list.as_rule() == Rule::parameter_list {
-            for param_pair in param_list.into_inner() {
-                parameters.push(parse_parameter(param_pair, input, file)?);
+    let mut return_type = None;
+    let mut body = Vec::new();
+    for part in inner {
+        match part.as_rule() {
+            Rule::parameter_list => {
+                for param_pair in part.into_inner() {
+                    parameters.push(parse_parameter(param_pair, input, file)?);
+                }
+            }
+            Rule::return_part => {
+                // The return_part rule contains: "->" ~ type_keyword
+                let type_pair = part.into_inner().next().unwrap();
+                return_type = Some(parse_type_keyword(type_pair));
             }
+            Rule::statement => body.push(parse_statement(input, file, part)?),
+            _ => {}
         }
     }
     // Check for duplicate parameter names
@@ -365,118 +932,122 @@ fn parse_function_definition(
             ));
         }
     }
-    // Skip ")"
-    let mut body = Vec::new();
-    let return_type = if let Some(next) = inner.next() {
-        if next.as_rule() == Rule::return_part {
-            let mut return_inner = next.into_inner();
-            // The return_part rule contains: "->" ~ type_keyword
-            // So the first (and only relevant) item should be the type_keyword
-            let type_pair = return_inner.next().unwrap();
-            Some(parse_type_keyword(type_pair, input, file)?)
-        } else if next.as_rule() == Rule::statement {
-            body.push(parse_statement(input, file, next)?);
-            None
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-    for stmt_pair in inner {
-        if stmt_pair.as_rule() == Rule::statement {
-            body.push(parse_statement(input, file, stmt_pair)?);
-        }
-    }
     Ok(FunctionDefinition {
         name,
         parameters,
         return_type,
         body,
+        location: Location::from_pest_span(input, &span, file.to_string()),
     })
 }
 
 fn parse_parameter(
     pair: pest::iterators::Pair<Rule>,
-    input: &str,
+    input: &SourceText,
     file: &str,
 ) -> Result<Parameter> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
+    let name = declared_name(inner.next().unwrap(), input, file)?;
     // Skip ":"
-    let param_type = parse_type_keyword(inner.next().unwrap(), input, file)?;
-    Ok(Parameter { name, param_type })
+    let param_type = parse_type_keyword(inner.next().unwrap());
+    Ok(Parameter {
+        name,
+        param_type,
+        location,
+    })
 }
 
-fn parse_type_keyword(pair: pest::iterators::Pair<Rule>, input: &str, file: &str) -> Result<Type> {
-    let type_str = pair.as_str();
-    match type_str {
-        "tamsayı" => Ok(Type::Tamsayi),
-        "metin" => Ok(Type::Metin),
-        "ondalıklı" => Ok(Type::Ondalikli),
-        "mantıksal" => Ok(Type::Mantiksal),
-        _ => Err(OtagError::syntax(
-            format!(
-                "Bilinmeyen tür '{}'. Geçerli türler: tamsayı, metin, ondalıklı, mantıksal",
-                type_str
-            ),
-            Location::from_pest_span(input, &pair.as_span(), file.to_string()),
-        )
-        .with_suggestions(vec![
-            "tamsayı".to_string(),
-            "metin".to_string(),
-            "ondalıklı".to_string(),
-            "mantıksal".to_string(),
-        ])),
+/// Names are compared in NFC form, so an identifier typed with combining
+/// marks (`O` + `◌̈`) is the same symbol as its precomposed spelling (`Ö`)
+fn identifier_name(pair: pest::iterators::Pair<Rule>) -> String {
+    pair.as_str().nfc().collect()
+}
+
+/// The name a statement introduces or assigns to, rejecting reserved words
+fn declared_name(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<String> {
+    if pair.as_rule() == Rule::reserved_name {
+        let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
+        return Err(keywords::reserved_name_error(pair.as_str(), location));
     }
+    Ok(identifier_name(pair))
 }
 
-fn parse_return_statement(pair: pest::iterators::Pair<Rule>) -> Result<Option<Expression>> {
+fn parse_type_keyword(pair: pest::iterators::Pair<Rule>) -> Type {
     let mut inner = pair.into_inner();
-    // Skip "return"
-    if let Some(expr_pair) = inner.next() {
-        Ok(Some(parse_expression(expr_pair)?))
-    } else {
-        Ok(None)
-    }
+    let base = match identifier_name(inner.next().unwrap()).as_str() {
+        "tamsayı" => Type::Tamsayi,
+        "metin" => Type::Metin,
+        "ondalıklı" => Type::Ondalikli,
+        "mantıksal" => Type::Mantiksal,
+        // The semantic analyzer reports struct names that are never defined
+        name => Type::Struct(name.to_string()),
+    };
+    // The remaining pairs are `dizisi` suffixes
+    inner.fold(base, |element, _| Type::Array(Box::new(element)))
 }
 
-fn parse_function_call(pair: pest::iterators::Pair<Rule>) -> Result<FunctionCall> {
+fn parse_return_statement(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<ReturnStatement> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
-    // Skip "("
-    let mut arguments = Vec::new();
-    if let Some(arg_list) = inner.next() {
-        if arg_list.as_rule() == Rule::argument_list {
-            for expr_pair in arg_list.into_inner() {
-                arguments.push(parse_expression(expr_pair)?);
-            }
-        }
+    // Skip "return"
+    let value = match inner.next() {
+        Some(expr_pair) => Some(parse_expression(expr_pair, input, file)?),
+        None => None,
+    };
+    Ok(ReturnStatement { value, location })
+}
+
+/// Arguments of a call, in the parentheses after the callee
+fn parse_arguments(
+    pair: pest::iterators::Pair<Rule>,
+    input: &SourceText,
+    file: &str,
+) -> Result<Vec<Expression>> {
+    match pair.into_inner().next() {
+        Some(arg_list) => arg_list
+            .into_inner()
+            .map(|expr_pair| parse_expression(expr_pair, input, file))
+            .collect(),
+        None => Ok(Vec::new()),
     }
-    // Skip ")"
-    Ok(FunctionCall { name, arguments })
 }
 
 fn parse_struct_definition(
     pair: pest::iterators::Pair<Rule>,
-    input: &str,
+    input: &SourceText,
     file: &str,
 ) -> Result<StructDefinition> {
+    let location = Location::from_pest_span(input, &pair.as_span(), file.to_string());
     let mut inner = pair.into_inner();
-    let name = inner.next().unwrap().as_str().to_string();
+    let name = declared_name(inner.next().unwrap(), input, file)?;
     let mut fields = Vec::new();
     for field_pair in inner {
         if field_pair.as_rule() == Rule::field_definition {
+            let location = Location::from_pest_span(input, &field_pair.as_span(), file.to_string());
             let mut field_inner = field_pair.into_inner();
-            let field_name = field_inner.next().unwrap().as_str().to_string();
-            let field_type = parse_type_keyword(field_inner.next().unwrap(), input, file)?;
+            let field_name = declared_name(field_inner.next().unwrap(), input, file)?;
+            let field_type = parse_type_keyword(field_inner.next().unwrap());
             fields.push(FieldDefinition {
                 name: field_name,
                 field_type,
+                location,
             });
         }
     }
-    Ok(StructDefinition { name, fields })
+    Ok(StructDefinition {
+        name,
+        fields,
+        location,
+    })
 }
 
 #[cfg(test)]
@@ -490,7 +1061,7 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::Assignment(assign) = &program.statements[0] {
             assert_eq!(assign.name, "isim");
-            if let Expression::Literal(VariableValue::Int(123)) = &assign.expression {
+            if let ExpressionKind::Literal(VariableValue::Int(123)) = &assign.expression.kind {
                 // ok
             } else {
                 panic!("Wrong expression");
@@ -500,13 +1071,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_place_assignment() {
+        let program = parse("sınıf.öğrenciler[i + 1].not = 90", "test.otag").unwrap();
+        let Statement::Assignment(assign) = &program.statements[0] else {
+            panic!("Not assignment");
+        };
+        assert_eq!(assign.name, "sınıf");
+        match assign.accessors.as_slice() {
+            [Accessor::Field(list), Accessor::Index(Expression {
+                kind: ExpressionKind::BinaryOp(_, BinaryOperator::Add, _),
+                ..
+            }), Accessor::Field(field)] => {
+                assert_eq!(list, "öğrenciler");
+                assert_eq!(field, "not");
+            }
+            other => panic!("Wrong accessors: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_parse_output() {
         let input = "söyle isim";
         let program = parse(input, "test.otag").unwrap();
         assert_eq!(program.statements.len(), 1);
         if let Statement::Output(out) = &program.statements[0] {
-            if let Expression::VariableRef(name) = &out.expression {
+            if let ExpressionKind::VariableRef(name) = &out.expression.kind {
                 assert_eq!(name, "isim");
             } else {
                 panic!("Wrong expression");
@@ -523,7 +1113,7 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::Assignment(assign) = &program.statements[0] {
             assert_eq!(assign.name, "mesaj");
-            if let Expression::Literal(VariableValue::String(s)) = &assign.expression {
+            if let ExpressionKind::Literal(VariableValue::String(s)) = &assign.expression.kind {
                 assert_eq!(s, "Merhaba");
             } else {
                 panic!("Wrong expression");
@@ -533,6 +1123,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_string_forms() {
+        let strings = |input: &str| -> Vec<String> {
+            parse(input, "test.otag")
+                .unwrap()
+                .statements
+                .into_iter()
+                .map(|statement| match statement {
+                    Statement::Output(OutputStatement {
+                        expression:
+                            Expression {
+                                kind: ExpressionKind::Literal(VariableValue::String(s)),
+                                ..
+                            },
+                        ..
+                    }) => s,
+                    other => panic!("Not a string output: {:?}", other),
+                })
+                .collect()
+        };
+        assert_eq!(
+            strings(r#"söyle "a\"b\n\t\\ # \u{e7}""#),
+            vec!["a\"b\n\t\\ # ç"]
+        );
+        assert_eq!(strings(r#"söyle r"C:\yeni\dosya""#), vec![r"C:\yeni\dosya"]);
+        assert_eq!(
+            strings("söyle \"\"\"\r\nbir\r\n\"iki\" üç\"\"\"\nsöyle \"\""),
+            vec!["bir\n\"iki\" üç", ""]
+        );
+    }
+
+    #[test]
+    fn test_parse_bad_escape_is_located() {
+        let err = parse("x = 1\nsöyle \"ab\\x\"", "test.otag").unwrap_err();
+        assert_eq!(err.message, "Geçersiz kaçış dizisi: \\x");
+        assert_eq!((err.location.line, err.location.column), (2, 10));
+
+        let err = parse(r#"söyle "\u{D800}""#, "test.otag").unwrap_err();
+        assert_eq!(err.message, "Geçersiz Unicode kaçışı: \\u{D800}");
+        let err = parse(r#"söyle "\u41""#, "test.otag").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Unicode kaçışı \\u{...} biçiminde yazılmalıdır"
+        );
+    }
+
+    #[test]
+    fn test_expressions_carry_their_span() {
+        let input = "x = 1\nsöyle (a + b) * liste[2]";
+        let program = parse(input, "test.otag").unwrap();
+        let Statement::Output(out) = &program.statements[1] else {
+            panic!("Not output");
+        };
+        let span = |expr: &Expression| &input[expr.location.start..expr.location.end];
+
+        assert_eq!(span(&out.expression), "(a + b) * liste[2]");
+        assert_eq!(
+            (out.expression.location.line, out.expression.location.column),
+            (2, 7)
+        );
+        let ExpressionKind::BinaryOp(left, BinaryOperator::Multiply, right) = &out.expression.kind
+        else {
+            panic!("Not multiplication");
+        };
+        assert_eq!(span(left), "(a + b)");
+        assert_eq!(span(right), "liste[2]");
+        let ExpressionKind::ArrayAccess(access) = &right.kind else {
+            panic!("Not array access");
+        };
+        assert_eq!(span(&access.index), "2");
+        assert_eq!(out.location.line, 2);
+    }
+
     #[test]
     fn test_parse_float_assignment() {
         let input = "değer = 2.5";
@@ -540,7 +1203,7 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::Assignment(assign) = &program.statements[0] {
             assert_eq!(assign.name, "değer");
-            if let Expression::Literal(VariableValue::Float(f)) = &assign.expression {
+            if let ExpressionKind::Literal(VariableValue::Float(f)) = &assign.expression.kind {
                 assert!((f - 2.5).abs() < 0.001);
             } else {
                 panic!("Wrong expression");
@@ -557,8 +1220,8 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::Assignment(assign) = &program.statements[0] {
             assert_eq!(assign.name, "dogru_mu");
-            if let Expression::Literal(VariableValue::Bool(b)) = &assign.expression {
-                assert_eq!(*b, true);
+            if let ExpressionKind::Literal(VariableValue::Bool(b)) = &assign.expression.kind {
+                assert!(*b);
             } else {
                 panic!("Wrong expression");
             }
@@ -574,13 +1237,15 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::Assignment(assign) = &program.statements[0] {
             assert_eq!(assign.name, "toplam");
-            if let Expression::BinaryOp(left, BinaryOperator::Add, right) = &assign.expression {
-                if let Expression::Literal(VariableValue::Int(5)) = &**left {
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::Add, right) =
+                &assign.expression.kind
+            {
+                if let ExpressionKind::Literal(VariableValue::Int(5)) = &left.kind {
                     // ok
                 } else {
                     panic!("Left not 5");
                 }
-                if let Expression::Literal(VariableValue::Int(3)) = &**right {
+                if let ExpressionKind::Literal(VariableValue::Int(3)) = &right.kind {
                     // ok
                 } else {
                     panic!("Right not 3");
@@ -593,6 +1258,195 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_operator_precedence() {
+        let input = "x = a + b * c";
+        let program = parse(input, "test.otag").unwrap();
+        if let Statement::Assignment(assign) = &program.statements[0] {
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::Add, right) =
+                &assign.expression.kind
+            {
+                assert!(matches!(&left.kind, ExpressionKind::VariableRef(name) if name == "a"));
+                assert!(matches!(
+                    &right.kind,
+                    ExpressionKind::BinaryOp(_, BinaryOperator::Multiply, _)
+                ));
+            } else {
+                panic!("Multiplication should bind tighter than addition");
+            }
+        } else {
+            panic!("Not assignment");
+        }
+    }
+
+    #[test]
+    fn test_parse_comparison_binds_loosest() {
+        let input = "eğer a + b < c - 1 ise\nson";
+        let program = parse(input, "test.otag").unwrap();
+        if let Statement::If(if_stmt) = &program.statements[0] {
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::LessThan, right) =
+                &if_stmt.condition.expression.kind
+            {
+                assert!(matches!(
+                    &left.kind,
+                    ExpressionKind::BinaryOp(_, BinaryOperator::Add, _)
+                ));
+                assert!(matches!(
+                    &right.kind,
+                    ExpressionKind::BinaryOp(_, BinaryOperator::Subtract, _)
+                ));
+            } else {
+                panic!("Condition not binary op <");
+            }
+        } else {
+            panic!("Not if statement");
+        }
+    }
+
+    #[test]
+    fn test_parse_power_is_right_associative() {
+        let input = "x = 2 ** 3 ** 2";
+        let program = parse(input, "test.otag").unwrap();
+        if let Statement::Assignment(assign) = &program.statements[0] {
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::Power, right) =
+                &assign.expression.kind
+            {
+                assert!(matches!(
+                    &left.kind,
+                    ExpressionKind::Literal(VariableValue::Int(2))
+                ));
+                assert!(matches!(
+                    &right.kind,
+                    ExpressionKind::BinaryOp(_, BinaryOperator::Power, _)
+                ));
+            } else {
+                panic!("Not power");
+            }
+        } else {
+            panic!("Not assignment");
+        }
+    }
+
+    #[test]
+    fn test_parse_logical_operators() {
+        let input = "eğer yaş >= 18 ve yaş <= 65 veya değil çalışıyor ise\nson";
+        let program = parse(input, "test.otag").unwrap();
+        if let Statement::If(if_stmt) = &program.statements[0] {
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::Or, right) =
+                &if_stmt.condition.expression.kind
+            {
+                if let ExpressionKind::BinaryOp(ge, BinaryOperator::And, le) = &left.kind {
+                    assert!(matches!(
+                        &ge.kind,
+                        ExpressionKind::BinaryOp(_, BinaryOperator::GreaterThanOrEqual, _)
+                    ));
+                    assert!(matches!(
+                        &le.kind,
+                        ExpressionKind::BinaryOp(_, BinaryOperator::LessThanOrEqual, _)
+                    ));
+                } else {
+                    panic!("'ve' should bind tighter than 'veya'");
+                }
+                assert!(matches!(
+                    &right.kind,
+                    ExpressionKind::UnaryOp(UnaryOperator::Not, _)
+                ));
+            } else {
+                panic!("Condition not 'veya'");
+            }
+        } else {
+            panic!("Not if statement");
+        }
+    }
+
+    #[test]
+    fn test_parse_keyword_prefix_is_identifier() {
+        // "veri" starts with "ve" but is a plain identifier
+        let input = "söyle veri";
+        let program = parse(input, "test.otag").unwrap();
+        if let Statement::Output(out) = &program.statements[0] {
+            assert!(
+                matches!(&out.expression.kind, ExpressionKind::VariableRef(name) if name == "veri")
+            );
+        } else {
+            panic!("Not output");
+        }
+    }
+
+    #[test]
+    fn test_parse_parenthesized_expression() {
+        let input = "x = (a + b) * c";
+        let program = parse(input, "test.otag").unwrap();
+        if let Statement::Assignment(assign) = &program.statements[0] {
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::Multiply, right) =
+                &assign.expression.kind
+            {
+                assert!(matches!(
+                    &left.kind,
+                    ExpressionKind::BinaryOp(_, BinaryOperator::Add, _)
+                ));
+                assert!(matches!(&right.kind, ExpressionKind::VariableRef(name) if name == "c"));
+            } else {
+                panic!("Parentheses should group the addition");
+            }
+        } else {
+            panic!("Not assignment");
+        }
+    }
+
+    #[test]
+    fn test_parse_negative_literals_and_negation() {
+        let input = "x = -5 + -2.5 * -y";
+        let program = parse(input, "test.otag").unwrap();
+        if let Statement::Assignment(assign) = &program.statements[0] {
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::Add, right) =
+                &assign.expression.kind
+            {
+                assert!(matches!(
+                    &left.kind,
+                    ExpressionKind::Literal(VariableValue::Int(-5))
+                ));
+                if let ExpressionKind::BinaryOp(factor, BinaryOperator::Multiply, negated) =
+                    &right.kind
+                {
+                    assert!(
+                        matches!(&factor.kind, ExpressionKind::Literal(VariableValue::Float(f)) if *f == -2.5)
+                    );
+                    assert!(matches!(
+                        &negated.kind,
+                        ExpressionKind::UnaryOp(UnaryOperator::Negate, _)
+                    ));
+                } else {
+                    panic!("Right side not multiplication");
+                }
+            } else {
+                panic!("Not addition");
+            }
+        } else {
+            panic!("Not assignment");
+        }
+    }
+
+    #[test]
+    fn test_parse_integer_literal_bounds() {
+        let program = parse("x = -2147483648", "test.otag").unwrap();
+        if let Statement::Assignment(assign) = &program.statements[0] {
+            assert!(matches!(
+                &assign.expression.kind,
+                ExpressionKind::Literal(VariableValue::Int(i32::MIN))
+            ));
+            assert_eq!(assign.expression.location.column, 5);
+        } else {
+            panic!("Not assignment");
+        }
+
+        let err = parse("x = 2147483648", "test.otag").unwrap_err();
+        assert_eq!(err.message, "Geçersiz tamsayı: 2147483648");
+        let err = parse("x = -2147483649", "test.otag").unwrap_err();
+        assert_eq!(err.message, "Geçersiz tamsayı: -2147483649");
+        assert!(parse("x = 1 - -2147483648", "test.otag").is_ok());
+    }
+
     #[test]
     fn test_parse_variable_declaration() {
         let input = "x'ı tamsayı olarak tanımla";
@@ -600,12 +1454,42 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::VariableDeclaration(decl) = &program.statements[0] {
             assert_eq!(decl.name, "x");
-            assert_eq!(decl.var_type, Type::Tamsayi);
+            assert_eq!(decl.var_type, Some(Type::Tamsayi));
+            assert!(decl.initializer.is_none());
         } else {
             panic!("Not variable declaration");
         }
     }
 
+    #[test]
+    fn test_parse_declarations_with_initializer() {
+        let input = "x'ı tamsayı olarak tanımla = 5\ndeğişken y = x + 1";
+        let program = parse(input, "test.otag").unwrap();
+        let Statement::VariableDeclaration(explicit) = &program.statements[0] else {
+            panic!("Not variable declaration");
+        };
+        assert_eq!(explicit.var_type, Some(Type::Tamsayi));
+        assert!(matches!(
+            explicit.initializer,
+            Some(Expression {
+                kind: ExpressionKind::Literal(VariableValue::Int(5)),
+                ..
+            })
+        ));
+        let Statement::VariableDeclaration(inferred) = &program.statements[1] else {
+            panic!("Not variable declaration");
+        };
+        assert_eq!(inferred.name, "y");
+        assert_eq!(inferred.var_type, None);
+        assert!(matches!(
+            inferred.initializer,
+            Some(Expression {
+                kind: ExpressionKind::BinaryOp(_, BinaryOperator::Add, _),
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_parse_if_statement() {
         let input = "eğer x > 5 ise\nsöyle \"Büyük\"\nson";
@@ -613,15 +1497,15 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::If(if_stmt) = &program.statements[0] {
             // Check condition
-            if let Expression::BinaryOp(left, BinaryOperator::GreaterThan, right) =
-                &*if_stmt.condition.expression
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::GreaterThan, right) =
+                &if_stmt.condition.expression.kind
             {
-                if let Expression::VariableRef(var) = &**left {
+                if let ExpressionKind::VariableRef(var) = &left.kind {
                     assert_eq!(var, "x");
                 } else {
                     panic!("Left not variable x");
                 }
-                if let Expression::Literal(VariableValue::Int(5)) = &**right {
+                if let ExpressionKind::Literal(VariableValue::Int(5)) = &right.kind {
                     // ok
                 } else {
                     panic!("Right not 5");
@@ -650,15 +1534,15 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::WhileLoop(while_loop) = &program.statements[0] {
             // Check condition
-            if let Expression::BinaryOp(left, BinaryOperator::LessThan, right) =
-                &*while_loop.condition.expression
+            if let ExpressionKind::BinaryOp(left, BinaryOperator::LessThan, right) =
+                &while_loop.condition.expression.kind
             {
-                if let Expression::VariableRef(var) = &**left {
+                if let ExpressionKind::VariableRef(var) = &left.kind {
                     assert_eq!(var, "x");
                 } else {
                     panic!("Left not variable x");
                 }
-                if let Expression::Literal(VariableValue::Int(5)) = &**right {
+                if let ExpressionKind::Literal(VariableValue::Int(5)) = &right.kind {
                     // ok
                 } else {
                     panic!("Right not 5");
@@ -687,7 +1571,7 @@ mod tests {
             assert_eq!(func.parameters[1].param_type, Type::Tamsayi);
             assert_eq!(func.return_type, None);
             assert_eq!(func.body.len(), 1);
-            if let Statement::Return(Some(_)) = &func.body[0] {
+            if let Statement::Return(ReturnStatement { value: Some(_), .. }) = &func.body[0] {
                 // ok
             } else {
                 panic!("Body not return");
@@ -703,15 +1587,15 @@ mod tests {
         let program = parse(input, "test.otag").unwrap();
         assert_eq!(program.statements.len(), 1);
         if let Statement::Output(out) = &program.statements[0] {
-            if let Expression::FunctionCall(call) = &out.expression {
-                assert_eq!(call.name, "topla");
+            if let ExpressionKind::FunctionCall(call) = &out.expression.kind {
+                assert_eq!(call.name(), Some("topla"));
                 assert_eq!(call.arguments.len(), 2);
-                if let Expression::Literal(VariableValue::Int(1)) = &call.arguments[0] {
+                if let ExpressionKind::Literal(VariableValue::Int(1)) = &call.arguments[0].kind {
                     // ok
                 } else {
                     panic!("Arg 0 not 1");
                 }
-                if let Expression::Literal(VariableValue::Int(2)) = &call.arguments[1] {
+                if let ExpressionKind::Literal(VariableValue::Int(2)) = &call.arguments[1].kind {
                     // ok
                 } else {
                     panic!("Arg 1 not 2");
@@ -724,6 +1608,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_call_statement() {
+        let input = "selamla(\"Dünya\")\nsöyle 1";
+        let program = parse(input, "test.otag").unwrap();
+        assert_eq!(program.statements.len(), 2);
+        if let Statement::Expression(Expression {
+            kind: ExpressionKind::FunctionCall(call),
+            ..
+        }) = &program.statements[0]
+        {
+            assert_eq!(call.name(), Some("selamla"));
+            assert_eq!(call.arguments.len(), 1);
+            assert_eq!(call.location.line, 1);
+        } else {
+            panic!("Not a call statement");
+        }
+    }
+
     #[test]
     fn test_parse_array_literal() {
         let input = "x = [1, 2, 3]";
@@ -731,19 +1633,22 @@ mod tests {
         assert_eq!(program.statements.len(), 1);
         if let Statement::Assignment(assign) = &program.statements[0] {
             assert_eq!(assign.name, "x");
-            if let Expression::ArrayLiteral(array_lit) = &assign.expression {
+            if let ExpressionKind::ArrayLiteral(array_lit) = &assign.expression.kind {
                 assert_eq!(array_lit.elements.len(), 3);
-                if let Expression::Literal(VariableValue::Int(1)) = &array_lit.elements[0] {
+                if let ExpressionKind::Literal(VariableValue::Int(1)) = &array_lit.elements[0].kind
+                {
                     // ok
                 } else {
                     panic!("First element not 1");
                 }
-                if let Expression::Literal(VariableValue::Int(2)) = &array_lit.elements[1] {
+                if let ExpressionKind::Literal(VariableValue::Int(2)) = &array_lit.elements[1].kind
+                {
                     // ok
                 } else {
                     panic!("Second element not 2");
                 }
-                if let Expression::Literal(VariableValue::Int(3)) = &array_lit.elements[2] {
+                if let ExpressionKind::Literal(VariableValue::Int(3)) = &array_lit.elements[2].kind
+                {
                     // ok
                 } else {
                     panic!("Third element not 3");
@@ -762,13 +1667,13 @@ mod tests {
         let program = parse(input, "test.otag").unwrap();
         assert_eq!(program.statements.len(), 1);
         if let Statement::Output(out) = &program.statements[0] {
-            if let Expression::ArrayAccess(access) = &out.expression {
-                if let Expression::VariableRef(name) = &*access.array {
+            if let ExpressionKind::ArrayAccess(access) = &out.expression.kind {
+                if let ExpressionKind::VariableRef(name) = &access.array.kind {
                     assert_eq!(name, "dizi");
                 } else {
                     panic!("Array not variable dizi");
                 }
-                if let Expression::Literal(VariableValue::Int(0)) = &*access.index {
+                if let ExpressionKind::Literal(VariableValue::Int(0)) = &access.index.kind {
                     // ok
                 } else {
                     panic!("Index not 0");
@@ -781,6 +1686,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_postfix_chains() {
+        let program = parse(
+            "söyle matris[i][j]\nsöyle al()[0]\nsöyle liste[0].isim",
+            "test.otag",
+        )
+        .unwrap();
+        let expressions: Vec<&Expression> = program
+            .statements
+            .iter()
+            .map(|statement| match statement {
+                Statement::Output(out) => &out.expression,
+                _ => panic!("Not output"),
+            })
+            .collect();
+
+        let ExpressionKind::ArrayAccess(outer) = &expressions[0].kind else {
+            panic!("Not array access");
+        };
+        assert!(matches!(&outer.index.kind, ExpressionKind::VariableRef(name) if name == "j"));
+        let ExpressionKind::ArrayAccess(inner) = &outer.array.kind else {
+            panic!("Not nested array access");
+        };
+        assert!(matches!(&inner.array.kind, ExpressionKind::VariableRef(name) if name == "matris"));
+
+        let ExpressionKind::ArrayAccess(access) = &expressions[1].kind else {
+            panic!("Not array access");
+        };
+        assert!(
+            matches!(&access.array.kind, ExpressionKind::FunctionCall(call) if call.name() == Some("al"))
+        );
+
+        let ExpressionKind::StructAccess(field) = &expressions[2].kind else {
+            panic!("Not field access");
+        };
+        assert_eq!(field.field_name, "isim");
+        assert!(matches!(
+            &field.struct_expr.kind,
+            ExpressionKind::ArrayAccess(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_calls_chain_like_other_postfixes() {
+        let program = parse(
+            "söyle a[0](x)
+f(x)(y)
+söyle al().isim",
+            "test.otag",
+        )
+        .unwrap();
+        let Statement::Output(out) = &program.statements[0] else {
+            panic!("Not output");
+        };
+        let ExpressionKind::FunctionCall(call) = &out.expression.kind else {
+            panic!("Not call");
+        };
+        assert_eq!(call.name(), None);
+        assert!(matches!(&call.callee.kind, ExpressionKind::ArrayAccess(_)));
+        assert_eq!(call.location.column, 7);
+        assert_eq!(call.location.end - call.location.start, "a[0](x)".len());
+
+        let Statement::Expression(Expression {
+            kind: ExpressionKind::FunctionCall(outer),
+            ..
+        }) = &program.statements[1]
+        else {
+            panic!("Not call statement");
+        };
+        assert!(
+            matches!(&outer.arguments[0].kind, ExpressionKind::VariableRef(name) if name == "y")
+        );
+        assert!(
+            matches!(&outer.callee.kind, ExpressionKind::FunctionCall(inner) if inner.name() == Some("f"))
+        );
+
+        let Statement::Output(out) = &program.statements[2] else {
+            panic!("Not output");
+        };
+        assert!(matches!(
+            &out.expression.kind,
+            ExpressionKind::StructAccess(_)
+        ));
+
+        // A statement must end in a call
+        assert!(parse("liste[0]", "test.otag").is_err());
+        assert!(parse("al().isim", "test.otag").is_err());
+    }
+
     #[test]
     fn test_parse_field_definition2() {
         let input = "yas: tamsayı";
@@ -795,6 +1789,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_array_types() {
+        let program = parse(
+            "matris'i tamsayı dizisi dizisi olarak tanımla\nsinif { ogrenciler: ogrenci dizisi }",
+            "test.otag",
+        )
+        .unwrap();
+        let Statement::VariableDeclaration(decl) = &program.statements[0] else {
+            panic!("Not variable declaration");
+        };
+        assert_eq!(
+            decl.var_type,
+            Some(Type::Array(Box::new(Type::Array(Box::new(Type::Tamsayi)))))
+        );
+        let Statement::StructDefinition(def) = &program.statements[1] else {
+            panic!("Not struct definition");
+        };
+        assert_eq!(
+            def.fields[0].field_type,
+            Type::Array(Box::new(Type::Struct("ogrenci".to_string())))
+        );
+    }
+
     #[test]
     fn test_parse_struct_definition() {
         let input = "ogrenci { isim: metin, yas: tamsayı }";
@@ -1,25 +1,108 @@
 use crate::ast::*;
+use crate::diagnostics::Diagnostics;
 use crate::error_reporting::*;
 use crate::location::*;
 use crate::symbol_table::SymbolTable;
+use crate::turkish::accusative_suffix;
+use crate::types::*;
+use std::collections::{HashMap, HashSet};
+
+/// What a function body touches outside itself
+#[derive(Default)]
+struct FunctionUses {
+    /// Globals read or assigned, with the first place each is used
+    globals: Vec<(String, Location)>,
+    /// Functions called from the body
+    calls: Vec<String>,
+}
 
 // Semantic analysis phase
 pub struct SemanticAnalyzer {
     symbol_table: SymbolTable,
+    /// Number of loops around the statement being analyzed
+    loop_depth: usize,
+    /// Return type of the function being analyzed: `None` at top level and
+    /// `Some(None)` inside a procedure
+    function_return: Option<Option<Type>>,
+    /// Errors found so far; analysis carries on past each of them
+    errors: Vec<OtagError>,
+    /// Problems found that do not stop the program
+    warnings: Vec<OtagError>,
+    /// Functions whose bodies are being analyzed, innermost last
+    enclosing_functions: Vec<String>,
+    function_uses: HashMap<String, FunctionUses>,
+    /// Position of each global among the global declarations
+    global_order: HashMap<String, usize>,
+    /// Calls made outside any function, with how many globals were declared
+    /// before them
+    top_level_calls: Vec<(String, Location, usize)>,
 }
 
 impl SemanticAnalyzer {
     pub fn new() -> Self {
         Self {
             symbol_table: SymbolTable::new(),
+            loop_depth: 0,
+            function_return: None,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            enclosing_functions: Vec::new(),
+            function_uses: HashMap::new(),
+            global_order: HashMap::new(),
+            top_level_calls: Vec::new(),
         }
     }
 
+    /// Warnings collected by the last analysis
+    #[allow(dead_code)]
+    pub fn warnings(&self) -> &[OtagError] {
+        &self.warnings
+    }
+
+    /// Analyze the whole program. Every problem is recorded and analysis
+    /// carries on past it; the result is the earliest error, and
+    /// `diagnostics` has all of them.
     pub fn analyze_program(&mut self, program: &Program) -> Result<()> {
+        self.declare_items(&program.statements);
         for statement in &program.statements {
-            self.analyze_statement(statement)?;
+            let result = match statement {
+                Statement::FunctionDefinition(func) => self.check_function(func),
+                Statement::StructDefinition(def) => self.check_struct(def),
+                _ => self.analyze_statement(statement),
+            };
+            self.check(result);
+        }
+        self.check_globals_declared_before_calls();
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+        // Errors are kept ahead of warnings, so the earliest one is always shown
+        let diagnostics = self.diagnostics();
+        let first = diagnostics.errors().next();
+        Err(first.expect("errors are never capped away").clone())
+    }
+
+    /// Errors and warnings found by the last analysis, sorted by location
+    pub fn diagnostics(&self) -> Diagnostics {
+        Diagnostics::new(self.errors.iter().chain(&self.warnings).cloned().collect())
+    }
+
+    /// Record an error and carry on with the analysis
+    fn report(&mut self, error: OtagError) {
+        self.errors.push(error);
+    }
+
+    fn check(&mut self, result: Result<()>) {
+        if let Err(error) = result {
+            self.report(error);
+        }
+    }
+
+    fn analyze_statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            let result = self.analyze_statement(statement);
+            self.check(result);
         }
-        Ok(())
     }
 
     fn analyze_statement(&mut self, statement: &Statement) -> Result<()> {
@@ -30,43 +113,930 @@ impl SemanticAnalyzer {
                 Ok(())
             }
             Statement::VariableDeclaration(decl) => {
-                // Check if variable already declared
-                if self.symbol_table.lookup(&decl.name).is_some() {
-                    return Err(OtagError::semantic(
+                // Check if variable already declared in this block; outer
+                // variables may be shadowed
+                if let Some(existing) = self.symbol_table.lookup_current_scope(&decl.name) {
+                    let error = OtagError::semantic(
                         format!("Değişken '{}' zaten tanımlanmış", decl.name),
-                        Location::unknown(),
-                    ));
+                        decl.location.clone(),
+                    );
+                    return Err(match &existing.location {
+                        Some(first) => error.with_label(first, "ilk tanım burada"),
+                        None => error,
+                    });
+                }
+                self.check_suffix(decl);
+                // The initializer cannot see the variable it initializes
+                let var_type = match (&decl.var_type, &decl.initializer) {
+                    (Some(declared), initializer) => {
+                        if let Some(initializer) = initializer {
+                            let found = self.infer_type_for(initializer, declared);
+                            self.check(expect_type(declared, &found, &initializer.location));
+                        }
+                        match self.check_type_exists(declared, &decl.location) {
+                            Ok(()) => declared.clone(),
+                            Err(error) => {
+                                self.report(error);
+                                Type::Unknown
+                            }
+                        }
+                    }
+                    (None, Some(initializer)) => self.infer_type(initializer),
+                    (None, None) => {
+                        unreachable!("inferred declarations always have an initializer")
+                    }
+                };
+                if self.symbol_table.in_global_scope() {
+                    let order = self.global_order.len();
+                    self.global_order.entry(decl.name.clone()).or_insert(order);
                 }
                 self.symbol_table
-                    .insert(decl.name.clone(), decl.var_type.clone());
+                    .insert(decl.name.clone(), var_type, &decl.location);
                 Ok(())
             }
             Statement::Assignment(assign) => {
-                // Check if variable is declared
-                if self.symbol_table.lookup(&assign.name).is_none() {
-                    return Err(OtagError::semantic(
-                        format!("Tanımlanmamış değişken: {}", assign.name),
-                        Location::unknown(),
-                    ));
-                }
-                // TODO: Type check assignment
+                let found_variable = self
+                    .symbol_table
+                    .lookup(&assign.name)
+                    .map(|variable| variable.var_type.clone());
+                let Some(declared) = found_variable else {
+                    let found = self.infer_type(&assign.expression);
+                    return Err(self.undeclared_assignment(assign, &found));
+                };
+                self.record_global_use(&assign.name, &assign.location);
+                let declared = assign
+                    .accessors
+                    .iter()
+                    .try_fold(declared, |target, accessor| match accessor {
+                        Accessor::Field(field) => self.field_type(&target, field, &assign.location),
+                        Accessor::Index(index) => {
+                            self.element_type(&target, index, &assign.location)
+                        }
+                    });
+                let found = match &declared {
+                    Ok(declared) => self.infer_type_for(&assign.expression, declared),
+                    Err(_) => self.infer_type(&assign.expression),
+                };
+                expect_type(&declared?, &found, &assign.expression.location)
+            }
+            Statement::Output(output) => {
+                self.infer_type(&output.expression);
                 Ok(())
             }
-            Statement::FunctionDefinition(func) => {
-                self.symbol_table
-                    .insert_function(func.clone())
-                    .map_err(|e| OtagError::semantic(e, Location::unknown()))?;
+            Statement::Return(ret) => self.check_return(ret),
+            Statement::Expression(Expression {
+                kind: ExpressionKind::FunctionCall(call),
+                ..
+            }) => self.check_call(call).map(|_| ()),
+            Statement::Expression(expr) => {
+                self.infer_type(expr);
                 Ok(())
             }
+            // Top-level items are declared by `declare_items`; these are nested
+            Statement::FunctionDefinition(func) => {
+                // A duplicate is reported, but its body is still checked
+                let result = self.declare_function(func);
+                self.check(result);
+                self.check_function(func)
+            }
             Statement::StructDefinition(def) => {
+                let result = self.declare_struct(def);
+                self.check(result);
+                self.check_struct(def)
+            }
+            Statement::If(if_stmt) => {
+                let result = self.check_condition(&if_stmt.condition);
+                self.check(result);
+                self.analyze_block(&if_stmt.then_block);
+                if let Some(else_block) = &if_stmt.else_block {
+                    self.analyze_block(else_block);
+                }
+                Ok(())
+            }
+            Statement::WhileLoop(while_loop) => {
+                let result = self.check_condition(&while_loop.condition);
+                self.check(result);
+                self.loop_depth += 1;
+                self.analyze_block(&while_loop.body);
+                self.loop_depth -= 1;
+                Ok(())
+            }
+            Statement::Break(location) => self.check_inside_loop("durdur", location),
+            Statement::Continue(location) => self.check_inside_loop("devam", location),
+            Statement::ForLoop(for_loop) => {
+                // The loop variable is a float as soon as one bound or the step is
+                let mut loop_type = Type::Tamsayi;
+                let bounds = [Some(&for_loop.range_start), Some(&for_loop.range_end)];
+                for bound in bounds.into_iter().chain([for_loop.step.as_ref()]).flatten() {
+                    match self.infer_type(bound) {
+                        Type::Ondalikli => loop_type = Type::Ondalikli,
+                        Type::Tamsayi | Type::Unknown => {}
+                        found => self.report(
+                            OtagError::semantic(
+                                format!(
+                                    "Döngü sınırları ve adımı sayısal olmalıdır, {} bulundu",
+                                    found
+                                ),
+                                bound.location.clone(),
+                            )
+                            .with_suggestions(vec!["Örnek: için i in 1 dan 10 ise".to_string()]),
+                        ),
+                    }
+                }
+                if let Some(step) = for_loop.step.as_ref().filter(|step| is_zero_literal(step)) {
+                    self.report(
+                        OtagError::semantic("Döngü adımı sıfır olamaz".to_string(), step.location.clone())
+                            .with_suggestions(vec![
+                                "Artan bir döngü için pozitif, azalan bir döngü için negatif adım kullanın"
+                                    .to_string(),
+                                "Örnek: için i in 10 dan 1 adım -1 ise".to_string(),
+                            ]),
+                    );
+                }
+
+                self.symbol_table.push_scope();
+                self.symbol_table.insert(
+                    for_loop.loop_variable.name.clone(),
+                    loop_type,
+                    &for_loop.location,
+                );
+                self.loop_depth += 1;
+                self.analyze_block(&for_loop.body);
+                self.loop_depth -= 1;
+                self.symbol_table.pop_scope();
+                Ok(())
+            }
+            Statement::ForEachLoop(for_each) => {
+                let element_type = match self.infer_type(&for_each.iterable) {
+                    Type::Array(element) => *element,
+                    Type::Metin => Type::Metin,
+                    Type::Unknown => Type::Unknown,
+                    found => {
+                        self.report(
+                            OtagError::semantic(
+                                format!(
+                                    "'için' döngüsü yalnızca diziler ve metinler üzerinde çalışır, {} bulundu",
+                                    found
+                                ),
+                                for_each.iterable.location.clone(),
+                            )
+                            .with_suggestions(vec![
+                                "Sayı aralıkları için: için i in 1 dan 10 ise".to_string(),
+                            ]),
+                        );
+                        Type::Unknown
+                    }
+                };
+                let name = &for_each.loop_variable.name;
+                if for_each.index_variable.as_ref() == Some(name) {
+                    self.report(OtagError::semantic(
+                        format!(
+                            "'{}' hem sıra hem eleman değişkeni olarak kullanılamaz",
+                            name
+                        ),
+                        for_each.location.clone(),
+                    ));
+                }
+
+                self.symbol_table.push_scope();
+                if let Some(index) = &for_each.index_variable {
+                    self.symbol_table
+                        .insert(index.clone(), Type::Tamsayi, &for_each.location);
+                }
                 self.symbol_table
-                    .insert_struct(def.clone())
-                    .map_err(|e| OtagError::semantic(e, Location::unknown()))?;
+                    .insert(name.clone(), element_type, &for_each.location);
+                self.loop_depth += 1;
+                self.analyze_block(&for_each.body);
+                self.loop_depth -= 1;
+                self.symbol_table.pop_scope();
                 Ok(())
             }
-            _ => Ok(()), // Other statements don't need semantic checks yet
         }
     }
+
+    /// `eğer` and `döngü` conditions must be `mantıksal`
+    fn check_condition(&mut self, condition: &Condition) -> Result<()> {
+        let found = self.infer_type(&condition.expression);
+        expect_type(&Type::Mantiksal, &found, &condition.location).map_err(|error| {
+            error.with_suggestions(vec![
+                "Koşul doğru ya da yanlış olmalıdır, örneğin: sayı > 0".to_string(),
+            ])
+        })
+    }
+
+    /// Check a `return` against the signature of the enclosing function
+    fn check_return(&mut self, ret: &ReturnStatement) -> Result<()> {
+        let expected = self.function_return.clone();
+        let found = match (&ret.value, &expected) {
+            (Some(expr), Some(Some(expected))) => Some(self.infer_type_for(expr, expected)),
+            (Some(expr), _) => Some(self.infer_type(expr)),
+            (None, _) => None,
+        };
+        match (&expected, found) {
+            // A top-level `return` ends the program; its value is ignored
+            (None, _) => Ok(()),
+            (Some(Some(expected)), Some(found)) => {
+                let value = ret.value.as_ref().unwrap();
+                expect_type(expected, &found, &value.location)
+            }
+            (Some(Some(expected)), None) => Err(OtagError::semantic(
+                format!(
+                    "Bu fonksiyon '{}' türünde bir değer döndürmelidir",
+                    expected
+                ),
+                ret.location.clone(),
+            )),
+            (Some(None), Some(_)) => Err(OtagError::semantic(
+                "Dönüş türü olmayan bir fonksiyon değer döndüremez".to_string(),
+                ret.location.clone(),
+            )
+            .with_suggestions(vec![
+                "Fonksiyon tanımına '-> tür' ekleyin".to_string(),
+                "Değer olmadan yalnızca 'return' yazın".to_string(),
+            ])),
+            (Some(None), None) => Ok(()),
+        }
+    }
+
+    /// Infer the type of an expression. Type errors inside it are reported
+    /// and make it `Type::Unknown`.
+    fn infer_type(&mut self, expr: &Expression) -> Type {
+        match self.expression_type(expr) {
+            Ok(found) => found,
+            Err(error) => {
+                self.report(error);
+                Type::Unknown
+            }
+        }
+    }
+
+    fn expression_type(&mut self, expr: &Expression) -> Result<Type> {
+        let location = &expr.location;
+        match &expr.kind {
+            ExpressionKind::Literal(value) => Ok(value.value_type()),
+            ExpressionKind::VariableRef(name) => {
+                self.record_global_use(name, location);
+                self.symbol_table
+                    .lookup(name)
+                    .map(|variable| variable.var_type.clone())
+                    .ok_or_else(|| OtagError::undefined_variable(name, location.clone()))
+            }
+            ExpressionKind::UnaryOp(op, operand) => {
+                let operand = self.infer_type(operand);
+                match op {
+                    _ if operand == Type::Unknown => Ok(operand),
+                    UnaryOperator::Negate if operand.is_numeric() => Ok(operand),
+                    UnaryOperator::Not if operand == Type::Mantiksal => Ok(operand),
+                    UnaryOperator::Negate => Err(OtagError::semantic(
+                        format!(
+                            "'-' işareti yalnızca sayılara uygulanabilir, {} bulundu",
+                            operand
+                        ),
+                        location.clone(),
+                    )),
+                    UnaryOperator::Not => Err(OtagError::type_mismatch(
+                        &Type::Mantiksal.to_string(),
+                        &operand.to_string(),
+                        location.clone(),
+                    )),
+                }
+            }
+            ExpressionKind::BinaryOp(left, op, right) => {
+                let left = self.infer_type(left);
+                let right = self.infer_type(right);
+                if left == Type::Unknown || right == Type::Unknown {
+                    return Ok(Type::Unknown);
+                }
+                binary_result_type(op, &left, &right).ok_or_else(|| {
+                    OtagError::semantic(
+                        format!(
+                            "'{}' işlemi {} ve {} türleri arasında yapılamaz",
+                            operator_symbol(op),
+                            left,
+                            right
+                        ),
+                        location.clone(),
+                    )
+                    .with_suggestions(operand_suggestions(op, &left, &right))
+                })
+            }
+            ExpressionKind::FunctionCall(call) => self.check_call(call)?.ok_or_else(|| {
+                let name = call.name().unwrap_or_default();
+                OtagError::semantic(
+                    format!(
+                        "'{}' fonksiyonu bir değer döndürmüyor, sonucu bir ifadede kullanılamaz",
+                        name
+                    ),
+                    call.location.clone(),
+                )
+                .with_suggestions(vec![
+                    format!("'{}(...)' çağrısını tek başına bir satırda yazın", name),
+                    "Değer döndürmesi için fonksiyona '-> tür' ekleyip 'return' kullanın"
+                        .to_string(),
+                ])
+            }),
+            ExpressionKind::ArrayLiteral(array) => {
+                let element_types: Vec<Type> = array
+                    .elements
+                    .iter()
+                    .map(|element| self.infer_type(element))
+                    .collect();
+                if element_types.contains(&Type::Unknown) {
+                    return Ok(Type::Unknown);
+                }
+                check_array_elements_types(&element_types)
+                    .map_err(|e| OtagError::semantic(e, location.clone()))
+            }
+            ExpressionKind::ArrayAccess(access) => {
+                let array = self.infer_type(&access.array);
+                self.element_type(&array, &access.index, location)
+            }
+            ExpressionKind::StructLiteral(literal) => {
+                let Some(def) = self
+                    .symbol_table
+                    .lookup_struct(&literal.struct_name)
+                    .cloned()
+                else {
+                    for field in &literal.fields {
+                        self.infer_type(&field.value);
+                    }
+                    return Err(OtagError::semantic(
+                        format!("Tanımlanmamış yapı: {}", literal.struct_name),
+                        location.clone(),
+                    ));
+                };
+                for (index, field) in literal.fields.iter().enumerate() {
+                    if let Some(first) = literal.fields[..index]
+                        .iter()
+                        .find(|f| f.name == field.name)
+                    {
+                        self.report(
+                            OtagError::semantic(
+                                format!("'{}' alanına birden fazla değer verilmiş", field.name),
+                                field.location.clone(),
+                            )
+                            .with_label(&first.location, "ilk değer burada"),
+                        );
+                    }
+                    let Some(declared) = def
+                        .fields
+                        .iter()
+                        .find(|candidate| candidate.name == field.name)
+                    else {
+                        self.infer_type(&field.value);
+                        self.report(unknown_field(
+                            &literal.struct_name,
+                            &field.name,
+                            &field.location,
+                        ));
+                        continue;
+                    };
+                    let found = self.infer_type_for(&field.value, &declared.field_type);
+                    self.check(expect_type(
+                        &declared.field_type,
+                        &found,
+                        &field.value.location,
+                    ));
+                }
+                for missing in def
+                    .fields
+                    .iter()
+                    .filter(|declared| !literal.fields.iter().any(|f| f.name == declared.name))
+                {
+                    self.report(OtagError::semantic(
+                        format!(
+                            "'{}' yapısının '{}' alanına değer verilmemiş",
+                            literal.struct_name, missing.name
+                        ),
+                        location.clone(),
+                    ));
+                }
+                Ok(Type::Struct(literal.struct_name.clone()))
+            }
+            ExpressionKind::StructAccess(access) => {
+                let struct_type = self.infer_type(&access.struct_expr);
+                self.field_type(&struct_type, &access.field_name, location)
+            }
+        }
+    }
+
+    /// Warn when the possessive suffix of a declaration breaks vowel harmony
+    fn check_suffix(&mut self, decl: &VariableDeclaration) {
+        let (Some(written), Some(expected)) = (&decl.suffix, accusative_suffix(&decl.name)) else {
+            return;
+        };
+        if written.text == expected {
+            return;
+        }
+        // Only the suffix changes; the rest of the declaration stays as written
+        self.warnings.push(
+            OtagError::warning(
+                format!(
+                    "Ünlü uyumu: {}{} yerine {}{} yazılmalı",
+                    decl.name, written.text, decl.name, expected
+                ),
+                written.location.clone(),
+            )
+            .with_suggestions(vec![format!("Şöyle yazın: {}{}", decl.name, expected)]),
+        );
+    }
+
+    /// Error for assigning to a name that was never declared, suggesting the
+    /// declaration forms
+    fn undeclared_assignment(&self, assign: &Assignment, found: &Type) -> OtagError {
+        let mut suggestions = Vec::new();
+        if assign.accessors.is_empty() {
+            if *found != Type::Unknown {
+                let suffix = accusative_suffix(&assign.name).unwrap_or_else(|| "'ı".to_string());
+                suggestions.push(format!(
+                    "Değişkeni tanımlayarak değer verin: {}{} {} olarak tanımla = ...",
+                    assign.name, suffix, found
+                ));
+            }
+            suggestions.push(format!(
+                "Türünü değerden çıkarmak için: değişken {} = ...",
+                assign.name
+            ));
+        }
+        OtagError::undefined_variable(&assign.name, assign.location.clone())
+            .with_suggestions(suggestions)
+    }
+
+    /// Like `infer_type`, but an empty array literal takes its type from the
+    /// place it is written to
+    fn infer_type_for(&mut self, expr: &Expression, expected: &Type) -> Type {
+        match (&expr.kind, expected) {
+            (ExpressionKind::ArrayLiteral(array), Type::Array(_)) if array.elements.is_empty() => {
+                expected.clone()
+            }
+            _ => self.infer_type(expr),
+        }
+    }
+
+    /// Type of the element of an array of type `array_type` at `index`
+    fn element_type(
+        &mut self,
+        array_type: &Type,
+        index: &Expression,
+        location: &Location,
+    ) -> Result<Type> {
+        let index_type = self.infer_type(index);
+        self.check(expect_type(&Type::Tamsayi, &index_type, &index.location));
+        match array_type {
+            Type::Array(element) => Ok((**element).clone()),
+            Type::Unknown => Ok(Type::Unknown),
+            other => Err(OtagError::semantic(
+                format!("Yalnızca diziler indekslenebilir, {} bulundu", other),
+                location.clone(),
+            )),
+        }
+    }
+
+    /// Type of the field `field` of a value of type `struct_type`
+    fn field_type(&self, struct_type: &Type, field: &str, location: &Location) -> Result<Type> {
+        match struct_type {
+            // An undefined struct type was reported where it was written
+            Type::Struct(name) => match self.symbol_table.lookup_struct(name) {
+                Some(def) => def
+                    .fields
+                    .iter()
+                    .find(|candidate| candidate.name == field)
+                    .map(|candidate| candidate.field_type.clone())
+                    .ok_or_else(|| unknown_field(name, field, location)),
+                None => Ok(Type::Unknown),
+            },
+            Type::Unknown => Ok(Type::Unknown),
+            other => Err(OtagError::semantic(
+                format!("'{}' alanına erişilemez: {} bir yapı değil", field, other),
+                location.clone(),
+            )),
+        }
+    }
+
+    /// A struct that contains itself, directly or through other structs,
+    /// would need an infinitely large value
+    fn check_struct_not_recursive(&self, def: &StructDefinition) -> Result<()> {
+        let mut pending: Vec<&Type> = def.fields.iter().map(|field| &field.field_type).collect();
+        let mut seen = Vec::new();
+        while let Some(field_type) = pending.pop() {
+            let Type::Struct(name) = field_type else {
+                continue;
+            };
+            if *name == def.name {
+                return Err(OtagError::semantic(
+                    format!("'{}' yapısı kendisini alan olarak içeremez", def.name),
+                    def.location.clone(),
+                ));
+            }
+            if seen.contains(&name) {
+                continue;
+            }
+            seen.push(name);
+            if let Some(inner) = self.symbol_table.lookup_struct(name) {
+                pending.extend(inner.fields.iter().map(|field| &field.field_type));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a call against the signature of the called function and return
+    /// its return type, which is `None` for procedures
+    fn check_call(&mut self, call: &FunctionCall) -> Result<Option<Type>> {
+        let Some(name) = call.name() else {
+            // There are no function values, so nothing else can be called
+            let callee = self.infer_type(&call.callee);
+            for argument in &call.arguments {
+                self.infer_type(argument);
+            }
+            if callee == Type::Unknown {
+                return Ok(Some(Type::Unknown));
+            }
+            return Err(OtagError::semantic(
+                format!("Bu ifade çağrılamaz: {} bir fonksiyon değil", callee),
+                call.callee.location.clone(),
+            )
+            .with_suggestions(vec![
+                "Yalnızca fonksiyonlar adlarıyla çağrılabilir, örneğin: topla(1, 2)".to_string(),
+            ]));
+        };
+        let signature = self
+            .symbol_table
+            .lookup_function(name)
+            .map(|func| (func.parameters.clone(), func.return_type.clone()));
+        let Some((parameters, return_type)) = signature else {
+            for argument in &call.arguments {
+                self.infer_type(argument);
+            }
+            return Err(OtagError::semantic(
+                format!("Tanımlanmamış fonksiyon: {}", name),
+                call.location.clone(),
+            ));
+        };
+        match self.enclosing_functions.last() {
+            Some(caller) => {
+                let uses = self.function_uses.entry(caller.clone()).or_default();
+                uses.calls.push(name.to_string());
+            }
+            None => self.top_level_calls.push((
+                name.to_string(),
+                call.location.clone(),
+                self.global_order.len(),
+            )),
+        }
+        if call.arguments.len() != parameters.len() {
+            self.report(OtagError::semantic(
+                format!(
+                    "'{}' fonksiyonu {} argüman bekliyor, {} verildi",
+                    name,
+                    parameters.len(),
+                    call.arguments.len()
+                ),
+                call.location.clone(),
+            ));
+            for argument in call.arguments.iter().skip(parameters.len()) {
+                self.infer_type(argument);
+            }
+        }
+        for (argument, param) in call.arguments.iter().zip(&parameters) {
+            let found = self.infer_type_for(argument, &param.param_type);
+            let result = expect_type(&param.param_type, &found, &argument.location).map_err(|e| {
+                OtagError::semantic(
+                    format!("'{}' parametresi için {}", param.name, e.message),
+                    *e.location,
+                )
+                .with_suggestions(e.suggestions)
+            });
+            self.check(result);
+        }
+        Ok(return_type)
+    }
+
+    /// Checks a function's signature and body once it has been declared
+    fn check_function(&mut self, func: &FunctionDefinition) -> Result<()> {
+        for param in &func.parameters {
+            let result = self.check_type_exists(&param.param_type, &param.location);
+            self.check(result);
+        }
+        if let Some(return_type) = &func.return_type {
+            let result = self.check_type_exists(return_type, &func.location);
+            self.check(result);
+        }
+
+        // Loops around the definition do not extend into the function body
+        let enclosing_loops = std::mem::take(&mut self.loop_depth);
+        let enclosing_return = self.function_return.replace(func.return_type.clone());
+        self.symbol_table.push_frame();
+        self.enclosing_functions.push(func.name.clone());
+        for param in &func.parameters {
+            self.symbol_table.insert(
+                param.name.clone(),
+                param.param_type.clone(),
+                &param.location,
+            );
+        }
+        self.analyze_statements(&func.body);
+        self.enclosing_functions.pop();
+        self.symbol_table.pop_frame();
+        self.function_return = enclosing_return;
+        self.loop_depth = enclosing_loops;
+
+        if let Some(return_type) = &func.return_type {
+            if !always_returns(&func.body) {
+                return Err(OtagError::semantic(
+                    format!(
+                        "'{}' fonksiyonu bazı yollarda değer döndürmüyor, ancak '{}' döndürmesi bekleniyor",
+                        func.name, return_type
+                    ),
+                    func.location.clone(),
+                )
+                .with_suggestions(vec![
+                    "Fonksiyonun sonuna bir 'return' ifadesi ekleyin".to_string(),
+                    "'eğer' bloğu kullanıyorsanız 'yoksa' kolunda da değer döndürün"
+                        .to_string(),
+                ]));
+            }
+        }
+        Ok(())
+    }
+
+    /// Note a global read or assigned by the function being analyzed
+    fn record_global_use(&mut self, name: &str, location: &Location) {
+        let Some(function) = self.enclosing_functions.last() else {
+            return;
+        };
+        if !self.symbol_table.is_global(name) {
+            return;
+        }
+        let uses = self.function_uses.entry(function.clone()).or_default();
+        if uses.globals.iter().all(|(global, _)| global != name) {
+            uses.globals.push((name.to_string(), location.clone()));
+        }
+    }
+
+    /// A function body sees the globals declared above its definition, but
+    /// the function can be called earlier, before one of them exists
+    fn check_globals_declared_before_calls(&mut self) {
+        for (function, call_location, declared) in std::mem::take(&mut self.top_level_calls) {
+            for (global, used_at) in self.globals_used_by(&function) {
+                if self
+                    .global_order
+                    .get(&global)
+                    .is_some_and(|&order| order < declared)
+                {
+                    continue;
+                }
+                self.report(
+                    OtagError::semantic(
+                        format!(
+                            "'{}' çağrıldığında kullandığı '{}' değişkeni henüz tanımlanmamış",
+                            function, global
+                        ),
+                        call_location.clone(),
+                    )
+                    .with_label(&used_at, "burada kullanılıyor")
+                    .with_suggestions(vec![format!(
+                        "'{}' değişkenini bu çağrıdan önce tanımlayın",
+                        global
+                    )]),
+                );
+            }
+        }
+    }
+
+    /// Globals a call to `function` uses, directly or through the functions
+    /// it calls
+    fn globals_used_by(&self, function: &str) -> Vec<(String, Location)> {
+        let mut globals: Vec<(String, Location)> = Vec::new();
+        let mut visited = HashSet::new();
+        let mut pending = vec![function];
+        while let Some(function) = pending.pop() {
+            if !visited.insert(function) {
+                continue;
+            }
+            let Some(uses) = self.function_uses.get(function) else {
+                continue;
+            };
+            for (global, location) in &uses.globals {
+                if globals.iter().all(|(seen, _)| seen != global) {
+                    globals.push((global.clone(), location.clone()));
+                }
+            }
+            pending.extend(uses.calls.iter().map(String::as_str));
+        }
+        globals
+    }
+
+    fn check_struct(&mut self, def: &StructDefinition) -> Result<()> {
+        for field in &def.fields {
+            let result = self.check_type_exists(&field.field_type, &field.location);
+            self.check(result);
+        }
+        self.check_struct_not_recursive(def)
+    }
+
+    /// Struct types used in declarations and signatures must be defined
+    fn check_type_exists(&self, var_type: &Type, location: &Location) -> Result<()> {
+        match var_type {
+            Type::Array(element) => self.check_type_exists(element, location),
+            Type::Struct(name) if self.symbol_table.lookup_struct(name).is_none() => Err(
+                OtagError::semantic(format!("Tanımlanmamış tür: {}", name), location.clone()),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Register every top-level function and struct before any statement is
+    /// analyzed, so they can be used above their definition and from modules
+    /// imported earlier
+    fn declare_items(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            let result = match statement {
+                Statement::FunctionDefinition(func) => self.declare_function(func),
+                Statement::StructDefinition(def) => self.declare_struct(def),
+                _ => Ok(()),
+            };
+            self.check(result);
+        }
+    }
+
+    fn declare_function(&mut self, func: &FunctionDefinition) -> Result<()> {
+        if let Some(existing) = self.symbol_table.lookup_function(&func.name) {
+            return Err(duplicate_definition(
+                "fonksiyon",
+                &func.name,
+                &existing.location,
+                &func.location,
+            ));
+        }
+        self.symbol_table
+            .insert_function(func.clone())
+            .map_err(|e| OtagError::semantic(e, func.location.clone()))
+    }
+
+    fn declare_struct(&mut self, def: &StructDefinition) -> Result<()> {
+        if let Some(existing) = self.symbol_table.lookup_struct(&def.name) {
+            return Err(duplicate_definition(
+                "yapı",
+                &def.name,
+                &existing.location,
+                &def.location,
+            ));
+        }
+        self.symbol_table
+            .insert_struct(def.clone())
+            .map_err(|e| OtagError::semantic(e, def.location.clone()))
+    }
+
+    fn analyze_block(&mut self, block: &ControlBlock) {
+        self.symbol_table.push_scope();
+        self.analyze_statements(&block.statements);
+        self.symbol_table.pop_scope();
+    }
+
+    /// `durdur` and `devam` only make sense inside `döngü` or `için`
+    fn check_inside_loop(&self, keyword: &str, location: &Location) -> Result<()> {
+        if self.loop_depth > 0 {
+            return Ok(());
+        }
+        Err(OtagError::semantic(
+            format!("'{}' yalnızca bir döngünün içinde kullanılabilir", keyword),
+            location.clone(),
+        )
+        .with_suggestions(vec![
+            format!(
+                "'{}' ifadesini bir 'döngü' ya da 'için' bloğunun içine taşıyın",
+                keyword
+            ),
+            "Bir fonksiyondan erken çıkmak için 'return' kullanın".to_string(),
+        ]))
+    }
+}
+
+fn duplicate_definition(kind: &str, name: &str, first: &Location, second: &Location) -> OtagError {
+    OtagError::semantic(
+        format!(
+            "'{}' adlı {} birden fazla kez tanımlanmış (ilk tanım: {})",
+            name, kind, first
+        ),
+        second.clone(),
+    )
+    .with_label(first, "ilk tanım burada")
+    .with_suggestions(vec![
+        "Tanımlardan birini silin ya da farklı bir adla yeniden adlandırın".to_string(),
+    ])
+}
+
+/// Require `found` to be exactly `expected`; there are no implicit conversions.
+/// `Type::Unknown` matches anything since its error is already reported.
+fn expect_type(expected: &Type, found: &Type, location: &Location) -> Result<()> {
+    if expected == found || *expected == Type::Unknown || *found == Type::Unknown {
+        return Ok(());
+    }
+    let error =
+        OtagError::type_mismatch(&expected.to_string(), &found.to_string(), location.clone());
+    if *expected == Type::Ondalikli && *found == Type::Tamsayi {
+        return Err(error.with_suggestions(vec![
+            "Tamsayıyı ondalıklı yazın, örneğin 5 yerine 5.0".to_string(),
+        ]));
+    }
+    Err(error)
+}
+
+/// Result type of a binary operation, or `None` when the operand types are
+/// not allowed. Mirrors the operations the interpreter supports.
+fn binary_result_type(op: &BinaryOperator, left: &Type, right: &Type) -> Option<Type> {
+    match op {
+        BinaryOperator::Add if *left == Type::Metin && *right == Type::Metin => Some(Type::Metin),
+        BinaryOperator::Add
+        | BinaryOperator::Subtract
+        | BinaryOperator::Multiply
+        | BinaryOperator::Divide
+        | BinaryOperator::Modulo
+        | BinaryOperator::Power
+            if left.is_numeric() && left == right =>
+        {
+            Some(left.clone())
+        }
+        BinaryOperator::GreaterThan
+        | BinaryOperator::GreaterThanOrEqual
+        | BinaryOperator::LessThan
+        | BinaryOperator::LessThanOrEqual
+            if left.is_numeric() && left == right =>
+        {
+            Some(Type::Mantiksal)
+        }
+        BinaryOperator::Equal | BinaryOperator::NotEqual if left == right => Some(Type::Mantiksal),
+        BinaryOperator::And | BinaryOperator::Or
+            if *left == Type::Mantiksal && *right == Type::Mantiksal =>
+        {
+            Some(Type::Mantiksal)
+        }
+        _ => None,
+    }
+}
+
+fn operator_symbol(op: &BinaryOperator) -> &'static str {
+    match op {
+        BinaryOperator::Add => "+",
+        BinaryOperator::Subtract => "-",
+        BinaryOperator::Multiply => "*",
+        BinaryOperator::Divide => "/",
+        BinaryOperator::Modulo => "%",
+        BinaryOperator::Power => "**",
+        BinaryOperator::Equal => "==",
+        BinaryOperator::NotEqual => "!=",
+        BinaryOperator::GreaterThan => ">",
+        BinaryOperator::GreaterThanOrEqual => ">=",
+        BinaryOperator::LessThan => "<",
+        BinaryOperator::LessThanOrEqual => "<=",
+        BinaryOperator::And => "ve",
+        BinaryOperator::Or => "veya",
+    }
+}
+
+fn operand_suggestions(op: &BinaryOperator, left: &Type, right: &Type) -> Vec<String> {
+    match op {
+        BinaryOperator::And | BinaryOperator::Or => {
+            vec!["'ve' ile 'veya' yalnızca mantıksal değerleri birleştirir".to_string()]
+        }
+        _ if left.is_numeric() && right.is_numeric() => vec![
+            "Tamsayı ve ondalıklı sayılar birlikte kullanılamaz; 5 yerine 5.0 yazın".to_string(),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+fn unknown_field(struct_name: &str, field: &str, location: &Location) -> OtagError {
+    OtagError::semantic(
+        format!("'{}' yapısında '{}' adlı bir alan yok", struct_name, field),
+        location.clone(),
+    )
+}
+
+/// Whether every path through `statements` ends in a `return`. Loop bodies
+/// may run zero times, so only `eğer` blocks with both branches count.
+fn always_returns(statements: &[Statement]) -> bool {
+    statements.iter().any(|statement| match statement {
+        Statement::Return(_) => true,
+        Statement::If(if_stmt) => match &if_stmt.else_block {
+            Some(else_block) => {
+                always_returns(&if_stmt.then_block.statements)
+                    && always_returns(&else_block.statements)
+            }
+            None => false,
+        },
+        _ => false,
+    })
+}
+
+/// `0`, `0.0` or their negation, which would make a counted loop never end
+fn is_zero_literal(expression: &Expression) -> bool {
+    match &expression.kind {
+        ExpressionKind::Literal(VariableValue::Int(0)) => true,
+        ExpressionKind::Literal(VariableValue::Float(f)) => *f == 0.0,
+        ExpressionKind::UnaryOp(UnaryOperator::Negate, operand) => is_zero_literal(operand),
+        _ => false,
+    }
 }
 
 impl Default for SemanticAnalyzer {
@@ -1,4 +1,5 @@
 use crate::ast::{FunctionDefinition, StructDefinition};
+use crate::location::Location;
 use crate::types::{Type, VariableValue};
 use std::collections::HashMap;
 
@@ -8,11 +9,18 @@ pub struct Variable {
     pub name: String,
     pub var_type: Type,
     pub value: Option<VariableValue>,
+    /// Where the variable is declared; only kept during analysis
+    pub location: Option<Location>,
 }
 
+/// Variables live in block scopes, innermost last; the first scope holds the
+/// globals. Each function call opens a frame, and code inside a frame only
+/// sees the frame's own scopes plus the globals.
 #[allow(dead_code)]
 pub struct SymbolTable {
     scopes: Vec<HashMap<String, Variable>>,
+    /// Index of the first scope of every active call, innermost last
+    frames: Vec<usize>,
     functions: HashMap<String, FunctionDefinition>,
     structs: HashMap<String, StructDefinition>,
 }
@@ -22,16 +30,29 @@ impl SymbolTable {
     pub fn new() -> Self {
         SymbolTable {
             scopes: vec![HashMap::new()],
+            frames: Vec::new(),
             functions: HashMap::new(),
             structs: HashMap::new(),
         }
     }
 
-    pub fn insert(&mut self, name: String, var_type: Type) {
+    pub fn insert(&mut self, name: String, var_type: Type, location: &Location) {
         let variable = Variable {
             name: name.clone(),
             var_type,
             value: None,
+            location: Some(location.clone()),
+        };
+        self.scopes.last_mut().unwrap().insert(name, variable);
+    }
+
+    /// Declare a variable in the current scope together with its value
+    pub fn insert_with_value(&mut self, name: String, var_type: Type, value: VariableValue) {
+        let variable = Variable {
+            name: name.clone(),
+            var_type,
+            value: Some(value),
+            location: None,
         };
         self.scopes.last_mut().unwrap().insert(name, variable);
     }
@@ -62,24 +83,33 @@ impl SymbolTable {
 
     #[allow(dead_code)]
     pub fn lookup(&self, name: &str) -> Option<&Variable> {
-        for scope in self.scopes.iter().rev() {
-            if let Some(var) = scope.get(name) {
-                return Some(var);
-            }
-        }
-        None
+        self.scope_of(name).map(|index| &self.scopes[index][name])
     }
 
+    /// Look a variable up in the innermost scope only, ignoring outer ones
+    pub fn lookup_current_scope(&self, name: &str) -> Option<&Variable> {
+        self.scopes.last().unwrap().get(name)
+    }
+
+    /// Set the value of a visible variable. Returns `false` when no variable
+    /// with that name is visible.
     #[allow(dead_code)]
-    pub fn update_value(&mut self, name: &str, value: VariableValue) {
-        for scope in self.scopes.iter_mut().rev() {
-            if let Some(var) = scope.get_mut(name) {
-                var.value = Some(value);
-                return;
+    pub fn update_value(&mut self, name: &str, value: VariableValue) -> bool {
+        match self.scope_of(name) {
+            Some(index) => {
+                self.scopes[index].get_mut(name).unwrap().value = Some(value);
+                true
             }
+            None => false,
         }
     }
 
+    /// Mutable access to the value of a visible variable
+    pub fn value_mut(&mut self, name: &str) -> Option<&mut VariableValue> {
+        let index = self.scope_of(name)?;
+        self.scopes[index].get_mut(name)?.value.as_mut()
+    }
+
     #[allow(dead_code)]
     pub fn push_scope(&mut self) {
         self.scopes.push(HashMap::new());
@@ -87,10 +117,53 @@ impl SymbolTable {
 
     #[allow(dead_code)]
     pub fn pop_scope(&mut self) {
-        if self.scopes.len() > 1 {
+        if self.scopes.len() > self.frame_base() + 1 {
             self.scopes.pop();
         }
     }
+
+    /// Enter a function call. The caller's local scopes become invisible
+    /// until the matching `pop_frame`.
+    pub fn push_frame(&mut self) {
+        self.frames.push(self.scopes.len());
+        self.scopes.push(HashMap::new());
+    }
+
+    /// Leave the innermost function call, dropping all of its scopes
+    pub fn pop_frame(&mut self) {
+        if let Some(base) = self.frames.pop() {
+            self.scopes.truncate(base);
+        }
+    }
+
+    /// Whether code inside a call sees `name` as a global variable
+    pub fn is_global(&self, name: &str) -> bool {
+        !self.frames.is_empty() && self.scope_of(name) == Some(0)
+    }
+
+    /// Whether declarations made now become globals
+    pub fn in_global_scope(&self) -> bool {
+        self.scopes.len() == 1
+    }
+
+    /// Number of function calls currently active
+    pub fn frame_depth(&self) -> usize {
+        self.frames.len()
+    }
+
+    fn frame_base(&self) -> usize {
+        self.frames.last().copied().unwrap_or(0)
+    }
+
+    /// Index of the scope that holds the visible variable called `name`
+    fn scope_of(&self, name: &str) -> Option<usize> {
+        let base = self.frame_base();
+        let globals = (base > 0).then_some(0);
+        (base..self.scopes.len())
+            .rev()
+            .chain(globals)
+            .find(|&index| self.scopes[index].contains_key(name))
+    }
 }
 
 impl Default for SymbolTable {
@@ -98,3 +171,39 @@ impl Default for SymbolTable {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_hides_caller_locals_but_not_globals() {
+        let mut table = SymbolTable::new();
+        table.insert_with_value("genel".to_string(), Type::Tamsayi, VariableValue::Int(1));
+        table.push_scope();
+        table.insert_with_value("yerel".to_string(), Type::Tamsayi, VariableValue::Int(2));
+
+        table.push_frame();
+        assert!(table.lookup("genel").is_some());
+        assert!(table.lookup("yerel").is_none());
+        assert!(!table.update_value("yerel", VariableValue::Int(3)));
+
+        table.pop_frame();
+        assert_eq!(
+            table.lookup("yerel").unwrap().value,
+            Some(VariableValue::Int(2))
+        );
+    }
+
+    #[test]
+    fn test_pop_frame_drops_all_frame_scopes() {
+        let mut table = SymbolTable::new();
+        table.push_frame();
+        table.push_scope();
+        table.insert("iç".to_string(), Type::Metin, &Location::unknown());
+        table.pop_frame();
+
+        assert!(table.lookup("iç").is_none());
+        assert_eq!(table.frame_depth(), 0);
+    }
+}
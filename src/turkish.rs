@@ -0,0 +1,73 @@
+//! Turkish spelling rules the language relies on
+
+/// Whether `c` is one of the eight Turkish vowels
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'ı' | 'i' | 'o' | 'ö' | 'u' | 'ü')
+}
+
+/// Lowercase a letter with the Turkish dotted and dotless i
+fn to_lower(c: char) -> char {
+    match c {
+        'I' => 'ı',
+        'İ' => 'i',
+        _ => c.to_lowercase().next().unwrap_or(c),
+    }
+}
+
+/// The accusative suffix that follows `name` by vowel harmony, such as
+/// `'ı` for `yaş`, `'yi` for `kişi` and `'u` for `sonuç`.
+///
+/// Returns `None` when the name does not end in a letter or has no vowel
+/// (`x`, `sayı2`), since the suffix then depends on how the name is read.
+pub fn accusative_suffix(name: &str) -> Option<String> {
+    let last = name.chars().next_back().filter(|c| c.is_alphabetic())?;
+    let last_vowel = name.chars().rev().map(to_lower).find(|c| is_vowel(*c))?;
+    let vowel = match last_vowel {
+        'a' | 'ı' => 'ı',
+        'e' | 'i' => 'i',
+        'o' | 'u' => 'u',
+        _ => 'ü',
+    };
+    // A buffer `y` separates the suffix from a final vowel
+    if is_vowel(to_lower(last)) {
+        Some(format!("'y{}", vowel))
+    } else {
+        Some(format!("'{}", vowel))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suffix_follows_last_vowel() {
+        assert_eq!(accusative_suffix("yaş").as_deref(), Some("'ı"));
+        assert_eq!(accusative_suffix("isim").as_deref(), Some("'i"));
+        assert_eq!(accusative_suffix("sonuç").as_deref(), Some("'u"));
+        assert_eq!(accusative_suffix("yüz").as_deref(), Some("'ü"));
+        assert_eq!(accusative_suffix("öğrenciler").as_deref(), Some("'i"));
+    }
+
+    #[test]
+    fn test_final_vowel_takes_buffer_y() {
+        assert_eq!(accusative_suffix("sayı").as_deref(), Some("'yı"));
+        assert_eq!(accusative_suffix("kişi").as_deref(), Some("'yi"));
+        assert_eq!(accusative_suffix("koku").as_deref(), Some("'yu"));
+        assert_eq!(accusative_suffix("ütü").as_deref(), Some("'yü"));
+        assert_eq!(accusative_suffix("araba").as_deref(), Some("'yı"));
+    }
+
+    #[test]
+    fn test_capital_dotted_and_dotless_i() {
+        assert_eq!(accusative_suffix("IŞIK").as_deref(), Some("'ı"));
+        assert_eq!(accusative_suffix("İSİM").as_deref(), Some("'i"));
+    }
+
+    #[test]
+    fn test_names_without_clear_reading() {
+        assert_eq!(accusative_suffix("x"), None);
+        assert_eq!(accusative_suffix("sayı2"), None);
+        assert_eq!(accusative_suffix("toplam_"), None);
+    }
+}
@@ -4,10 +4,31 @@ pub enum Type {
     Metin,     // string
     Ondalikli, // float
     Mantiksal, // boolean
-    #[allow(dead_code)]
     Array(Box<Type>),
-    #[allow(dead_code)]
     Struct(String),
+    /// Type of an expression that already has an error. It is accepted
+    /// everywhere, so one mistake is reported only once.
+    Unknown,
+}
+
+impl Type {
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Type::Tamsayi | Type::Ondalikli)
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Type::Tamsayi => write!(f, "tamsayı"),
+            Type::Metin => write!(f, "metin"),
+            Type::Ondalikli => write!(f, "ondalıklı"),
+            Type::Mantiksal => write!(f, "mantıksal"),
+            Type::Array(element) => write!(f, "{} dizisi", element),
+            Type::Struct(name) => write!(f, "{}", name),
+            Type::Unknown => write!(f, "bilinmeyen"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -17,61 +38,116 @@ pub enum VariableValue {
     Float(f64),
     Bool(bool),
     Array(Vec<VariableValue>),
+    /// Struct instance with its fields in definition order
+    Struct {
+        name: String,
+        fields: Vec<(String, VariableValue)>,
+    },
 }
 
-#[derive(Debug, Clone, PartialEq)]
-#[allow(dead_code)]
-pub enum ErrorType {
-    Syntax,
-    Semantic,
-    Runtime,
-    TypeMismatch,
-    UndefinedVariable,
-    DivisionByZero,
-}
+impl VariableValue {
+    /// Type of a runtime value. Empty arrays report `tamsayı` elements.
+    pub fn value_type(&self) -> Type {
+        match self {
+            VariableValue::Int(_) => Type::Tamsayi,
+            VariableValue::String(_) => Type::Metin,
+            VariableValue::Float(_) => Type::Ondalikli,
+            VariableValue::Bool(_) => Type::Mantiksal,
+            VariableValue::Array(elements) => Type::Array(Box::new(
+                elements
+                    .first()
+                    .map(VariableValue::value_type)
+                    .unwrap_or(Type::Tamsayi),
+            )),
+            VariableValue::Struct { name, .. } => Type::Struct(name.clone()),
+        }
+    }
 
-use crate::ast::Expression;
+    /// Value of a struct field, `None` for other values and unknown fields
+    pub fn field(&self, field: &str) -> Option<&VariableValue> {
+        match self {
+            VariableValue::Struct { fields, .. } => fields
+                .iter()
+                .find(|(name, _)| name == field)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
 
-#[allow(dead_code)]
-pub fn check_array_elements_types(elements: &[Expression]) -> Result<Type, String> {
-    if elements.is_empty() {
-        return Err("Empty arrays not supported".to_string());
+    pub fn field_mut(&mut self, field: &str) -> Option<&mut VariableValue> {
+        match self {
+            VariableValue::Struct { fields, .. } => fields
+                .iter_mut()
+                .find(|(name, _)| name == field)
+                .map(|(_, value)| value),
+            _ => None,
+        }
     }
-    let first_type = get_expression_type(&elements[0]);
-    for elem in &elements[1..] {
-        let elem_type = get_expression_type(elem);
-        if elem_type != first_type {
-            return Err("All array elements must be of the same type".to_string());
+
+    /// Write a value nested inside an array or struct, quoting text
+    fn fmt_nested(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            VariableValue::String(s) => write!(f, "\"{}\"", s),
+            other => write!(f, "{}", other),
         }
     }
-    Ok(Type::Array(Box::new(first_type)))
 }
 
-#[allow(dead_code)]
-fn get_expression_type(expr: &Expression) -> Type {
-    match expr {
-        Expression::Literal(val) => match val {
-            VariableValue::Int(_) => Type::Tamsayi,
-            VariableValue::String(_) => Type::Metin,
-            VariableValue::Float(_) => Type::Ondalikli,
-            VariableValue::Bool(_) => Type::Mantiksal,
-            VariableValue::Array(_) => Type::Array(Box::new(Type::Tamsayi)), // placeholder
-        },
-        _ => Type::Tamsayi, // placeholder
+impl std::fmt::Display for VariableValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            VariableValue::Int(i) => write!(f, "{}", i),
+            VariableValue::String(s) => write!(f, "{}", s),
+            VariableValue::Float(x) => write!(f, "{}", x),
+            VariableValue::Bool(b) => write!(f, "{}", if *b { "doğru" } else { "yanlış" }),
+            VariableValue::Array(elements) => {
+                write!(f, "[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    element.fmt_nested(f)?;
+                }
+                write!(f, "]")
+            }
+            VariableValue::Struct { name, fields } => {
+                write!(f, "{} {{", name)?;
+                for (i, (field, value)) in fields.iter().enumerate() {
+                    write!(f, "{} {}: ", if i > 0 { "," } else { "" }, field)?;
+                    value.fmt_nested(f)?;
+                }
+                write!(f, " }}")
+            }
+        }
     }
 }
 
+/// Type of an array whose elements have the given types. All elements must
+/// share one type.
+pub fn check_array_elements_types(element_types: &[Type]) -> Result<Type, String> {
+    let Some(first_type) = element_types.first() else {
+        return Err("Boş dizilerin türü belirlenemiyor".to_string());
+    };
+    if let Some(other) = element_types.iter().find(|t| *t != first_type) {
+        return Err(format!(
+            "Dizinin tüm elemanları aynı türde olmalıdır: {} ve {} bulundu",
+            first_type, other
+        ));
+    }
+    Ok(Type::Array(Box::new(first_type.clone())))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_array_type_check() {
-        let elements = vec![
-            Expression::Literal(VariableValue::Int(1)),
-            Expression::Literal(VariableValue::Int(2)),
-        ];
+        let elements = vec![Type::Tamsayi, Type::Tamsayi];
         let result = check_array_elements_types(&elements);
         assert_eq!(result, Ok(Type::Array(Box::new(Type::Tamsayi))));
+
+        let mixed = vec![Type::Tamsayi, Type::Metin];
+        assert!(check_array_elements_types(&mixed).is_err());
     }
 }
@@ -0,0 +1,22 @@
+// Helpers shared by the integration tests. Not every test file uses every
+// helper, so unused ones are allowed here.
+#![allow(dead_code)]
+
+use otag::error_reporting::{ErrorType, OtagError};
+use otag::OtagRuntime;
+
+pub fn run(source: &str) -> Vec<String> {
+    OtagRuntime::execute_inline_captured(source).unwrap()
+}
+
+pub fn semantic_error(source: &str) -> OtagError {
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(err.error_type, ErrorType::Semantic, "{}", err);
+    err
+}
+
+pub fn syntax_error(source: &str) -> OtagError {
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(err.error_type, ErrorType::Syntax, "{}", err);
+    err
+}
@@ -0,0 +1,232 @@
+// Integration tests for array types, array values and indexing
+
+mod common;
+
+use common::{run, semantic_error};
+use otag::error_reporting::{ErrorType, RuntimeErrorKind};
+use otag::OtagRuntime;
+
+#[test]
+fn test_declared_array_starts_empty() {
+    let output = run(r#"
+sayılar'ı tamsayı dizisi olarak tanımla
+söyle sayılar
+sayılar = [1, 2, 3]
+söyle sayılar
+sayılar = []
+söyle sayılar
+"#);
+    assert_eq!(output, vec!["[]", "[1, 2, 3]", "[]"]);
+}
+
+#[test]
+fn test_array_parameter_and_return_type() {
+    let output = run(r#"
+fonksiyon ortalama(notlar: ondalıklı dizisi) -> ondalıklı {
+    return (notlar[0] + notlar[1] + notlar[2]) / 3.0
+}
+
+fonksiyon ilk_iki(sayılar: tamsayı dizisi) -> tamsayı dizisi {
+    return [sayılar[0], sayılar[1]]
+}
+
+notlar'ı ondalıklı dizisi olarak tanımla
+notlar = [70.0, 80.0, 90.0]
+söyle ortalama(notlar)
+söyle ilk_iki([5, 6, 7])
+"#);
+    assert_eq!(output, vec!["80", "[5, 6]"]);
+}
+
+#[test]
+fn test_element_assignment_updates_array() {
+    let output = run(r#"
+sayılar'ı tamsayı dizisi olarak tanımla
+sayılar = [1, 2, 3]
+sayılar[1] = 10
+söyle sayılar[1]
+söyle sayılar
+"#);
+    assert_eq!(output, vec!["10", "[1, 10, 3]"]);
+}
+
+#[test]
+fn test_nested_array_type() {
+    let output = run(r#"
+matris'i tamsayı dizisi dizisi olarak tanımla
+matris = [[1, 2], [3, 4]]
+matris[1][0] = 30
+söyle matris
+"#);
+    assert_eq!(output, vec!["[[1, 2], [30, 4]]"]);
+}
+
+#[test]
+fn test_array_of_structs_as_field() {
+    let output = run(r#"
+Ogrenci {
+    isim: metin,
+    not: tamsayı
+}
+
+Sinif {
+    ogrenciler: Ogrenci dizisi
+}
+
+sinif'i Sinif olarak tanımla
+sinif.ogrenciler = [Ogrenci { isim: "Ali", not: 70 }, Ogrenci { isim: "Ece", not: 80 }]
+sinif.ogrenciler[1].not = 95
+söyle sinif
+"#);
+    assert_eq!(
+        output,
+        vec![
+            "Sinif { ogrenciler: [Ogrenci { isim: \"Ali\", not: 70 }, Ogrenci { isim: \"Ece\", not: 95 }] }"
+        ]
+    );
+}
+
+#[test]
+fn test_out_of_bounds_assignment_is_located_runtime_error() {
+    let err = OtagRuntime::execute_inline(
+        r#"
+sayılar'ı tamsayı dizisi olarak tanımla
+sayılar = [1, 2, 3]
+sayılar[3] = 4
+"#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.error_type,
+        ErrorType::Runtime(RuntimeErrorKind::IndexOutOfBounds)
+    );
+    assert_eq!(
+        err.message,
+        "Dizi sınırlarının dışında: 3 indeksi, dizinin uzunluğu 3"
+    );
+    assert_eq!(err.location.line, 4);
+}
+
+#[test]
+fn test_wrong_element_type_is_rejected() {
+    let err = semantic_error(
+        r#"
+notlar'ı ondalıklı dizisi olarak tanımla
+notlar = [1, 2]
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: ondalıklı dizisi bekleniyordu, tamsayı dizisi bulundu"
+    );
+}
+
+#[test]
+fn test_element_assignment_checks_element_type() {
+    let err = semantic_error(
+        r#"
+sayılar'ı tamsayı dizisi olarak tanımla
+sayılar[0] = "bir"
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: tamsayı bekleniyordu, metin bulundu"
+    );
+}
+
+#[test]
+fn test_indexing_a_non_array_is_rejected() {
+    let err = semantic_error(
+        r#"
+x'ı tamsayı olarak tanımla
+x[0] = 1
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "Yalnızca diziler indekslenebilir, tamsayı bulundu"
+    );
+}
+
+#[test]
+fn test_array_of_unknown_struct_is_rejected() {
+    let err = semantic_error("liste'yi Yok dizisi olarak tanımla");
+    assert_eq!(err.message, "Tanımlanmamış tür: Yok");
+}
+
+#[test]
+fn test_nested_indexing_on_grid() {
+    let output = run(r#"
+izgara'yı tamsayı dizisi dizisi olarak tanımla
+izgara = [[1, 2, 3], [4, 5, 6]]
+toplam'ı tamsayı olarak tanımla
+için i in 0 dan 1 ise
+    için j in 0 dan 2 ise
+        toplam = toplam + izgara[i][j]
+    son
+son
+söyle toplam
+söyle izgara[1][2]
+"#);
+    assert_eq!(output, vec!["21", "6"]);
+}
+
+#[test]
+fn test_indexing_call_result_and_field_of_element() {
+    let output = run(r#"
+Ogrenci {
+    isim: metin,
+    not: tamsayı
+}
+
+fonksiyon asallar() -> tamsayı dizisi {
+    return [2, 3, 5, 7]
+}
+
+fonksiyon liste() -> Ogrenci dizisi {
+    return [Ogrenci { isim: "Ali", not: 70 }, Ogrenci { isim: "Ece", not: 80 }]
+}
+
+söyle asallar()[2]
+söyle liste()[1].isim
+söyle (asallar()[0] + asallar()[3]) * 2
+"#);
+    assert_eq!(output, vec!["5", "Ece", "18"]);
+}
+
+#[test]
+fn test_only_function_names_can_be_called() {
+    let err = semantic_error("değişken liste = [1, 2]\nsöyle liste[0](3)");
+    assert_eq!(
+        err.message,
+        "Bu ifade çağrılamaz: tamsayı bir fonksiyon değil"
+    );
+    assert_eq!((err.location.line, err.location.column), (2, 7));
+
+    let err = semantic_error(
+        r#"
+fonksiyon iki() -> tamsayı {
+    return 2
+}
+iki()(1)
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "Bu ifade çağrılamaz: tamsayı bir fonksiyon değil"
+    );
+}
+
+#[test]
+fn test_out_of_bounds_read_is_runtime_error() {
+    let err = OtagRuntime::execute_inline("söyle [1, 2][5]").unwrap_err();
+    assert_eq!(
+        err.error_type,
+        ErrorType::Runtime(RuntimeErrorKind::IndexOutOfBounds)
+    );
+    assert_eq!(
+        err.message,
+        "Dizi sınırlarının dışında: 5 indeksi, dizinin uzunluğu 2"
+    );
+}
@@ -0,0 +1,191 @@
+// Integration tests for variable declarations with initial values and
+// inferred types
+
+mod common;
+
+use common::{run, semantic_error};
+use otag::error_reporting::ErrorType;
+use otag::OtagRuntime;
+
+#[test]
+fn test_declaration_with_initializer() {
+    let output = run(r#"
+x'ı tamsayı olarak tanımla = 5
+isim'i metin olarak tanımla = "Ada" + " " + "Lovelace"
+notlar'ı ondalıklı dizisi olarak tanımla = [1.5, 2.5]
+boş'u tamsayı dizisi olarak tanımla = []
+söyle x * 2
+söyle isim
+söyle notlar
+söyle boş
+"#);
+    assert_eq!(output, vec!["10", "Ada Lovelace", "[1.5, 2.5]", "[]"]);
+}
+
+#[test]
+fn test_initializer_must_match_declared_type() {
+    let err = semantic_error(r#"puan'ı ondalıklı olarak tanımla = 85"#);
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: ondalıklı bekleniyordu, tamsayı bulundu"
+    );
+    assert!(err.suggestions.iter().any(|s| s.contains("5.0")));
+}
+
+#[test]
+fn test_inferred_declaration_takes_initializer_type() {
+    let output = run(r#"
+değişken sayaç = 0
+değişken oran = 2.5
+değişken ad = "Zeynep"
+değişken bitti = yanlış
+değişken kareler = [1, 4, 9]
+sayaç = sayaç + 1
+söyle sayaç
+söyle oran * 2.0
+söyle ad
+söyle bitti
+söyle kareler[2]
+"#);
+    assert_eq!(output, vec!["1", "5", "Zeynep", "yanlış", "9"]);
+
+    let err = semantic_error(
+        r#"
+değişken oran = 2.5
+oran = 1
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: ondalıklı bekleniyordu, tamsayı bulundu"
+    );
+}
+
+#[test]
+fn test_inferred_struct_declaration() {
+    let output = run(r#"
+Nokta {
+    x: tamsayı,
+    y: tamsayı
+}
+
+değişken n = Nokta { x: 3, y: 4 }
+n.x = n.x + n.y
+söyle n.x
+"#);
+    assert_eq!(output, vec!["7"]);
+}
+
+#[test]
+fn test_inferred_empty_array_is_rejected() {
+    let err = semantic_error("değişken liste = []");
+    assert_eq!(err.message, "Boş dizilerin türü belirlenemiyor");
+}
+
+#[test]
+fn test_initializer_sees_shadowed_outer_variable() {
+    let output = run(r#"
+değişken x = 1
+eğer doğru ise
+    değişken x = x + 10
+    söyle x
+son
+söyle x
+"#);
+    assert_eq!(output, vec!["11", "1"]);
+}
+
+#[test]
+fn test_initializer_cannot_use_variable_being_declared() {
+    let err = semantic_error("değişken x = x + 1");
+    assert_eq!(err.message, "Tanımlanmamış değişken: x");
+}
+
+#[test]
+fn test_keyword_prefix_is_still_an_identifier() {
+    let output = run(r#"
+değişkenler'i tamsayı olarak tanımla = 3
+değişkenler = değişkenler + 1
+söyle değişkenler
+"#);
+    assert_eq!(output, vec!["4"]);
+}
+
+#[test]
+fn test_assignment_to_undeclared_variable_suggests_declaration() {
+    let err = semantic_error(r#"mesaj = "Merhaba""#);
+    assert_eq!(err.message, "Tanımlanmamış değişken: mesaj");
+    assert_eq!(err.location.line, 1);
+    assert!(
+        err.suggestions
+            .iter()
+            .any(|s| s.contains("mesaj'ı metin olarak tanımla = ...")),
+        "{:?}",
+        err.suggestions
+    );
+    assert!(err
+        .suggestions
+        .iter()
+        .any(|s| s.contains("değişken mesaj = ...")));
+}
+
+#[test]
+fn test_every_accusative_suffix_is_accepted() {
+    let output = run(r#"
+yaş'ı tamsayı olarak tanımla = 1
+isim'i metin olarak tanımla = "a"
+sonuç'u tamsayı olarak tanımla = 2
+yüz'ü tamsayı olarak tanımla = 3
+sayı'yı tamsayı olarak tanımla = 4
+kişi'yi metin olarak tanımla = "b"
+koku'yu metin olarak tanımla = "c"
+ütü'yü mantıksal olarak tanımla = doğru
+söyle yaş + sonuç + yüz + sayı
+söyle isim + kişi + koku
+söyle ütü
+"#);
+    assert_eq!(output, vec!["10", "abc", "doğru"]);
+}
+
+#[test]
+fn test_correct_suffixes_give_no_warnings() {
+    // `x` has no vowel, so any suffix is accepted
+    let warnings = OtagRuntime::analyze_inline(
+        r#"
+sayı'yı tamsayı olarak tanımla
+isim'i metin olarak tanımla
+x'i tamsayı olarak tanımla
+y'ı tamsayı olarak tanımla
+"#,
+    )
+    .unwrap();
+    assert!(warnings.is_empty(), "{:?}", warnings);
+}
+
+#[test]
+fn test_suffix_against_vowel_harmony_is_a_warning_with_fix() {
+    let source = r#"
+sonuç'ı tamsayı olarak tanımla = 3
+kişi'i metin olarak tanımla
+söyle sonuç
+"#;
+    let warnings = OtagRuntime::analyze_inline(source).unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].error_type, ErrorType::Warning);
+    assert_eq!(
+        warnings[0].message,
+        "Ünlü uyumu: sonuç'ı yerine sonuç'u yazılmalı"
+    );
+    // Only the suffix is underlined and replaced, so `= 3` is kept
+    let location = &warnings[0].location;
+    assert_eq!((location.line, location.column), (2, 6));
+    assert_eq!(&source[location.start..location.end], "'ı");
+    assert_eq!(warnings[0].suggestions, vec!["Şöyle yazın: sonuç'u"]);
+    assert_eq!(
+        warnings[1].message,
+        "Ünlü uyumu: kişi'i yerine kişi'yi yazılmalı"
+    );
+
+    // Warnings do not stop the program
+    assert_eq!(run(source), vec!["3"]);
+}
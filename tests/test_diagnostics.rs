@@ -0,0 +1,232 @@
+// Integration tests for rendered diagnostics: source snippets, carets,
+// secondary labels, collecting every error of a run and the `--renk` switch
+
+use otag::OtagRuntime;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn test_duplicate_variable_points_at_first_definition() {
+    let source = "değişken sayaç = 0\nsöyle sayaç\ndeğişken sayaç = 1\n";
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(err.labels.len(), 1);
+    assert_eq!(err.labels[0].location.line, 1);
+
+    let rendered = OtagRuntime::render_inline_error(source, &err, false);
+    assert_eq!(
+        rendered,
+        "hata: Değişken 'sayaç' zaten tanımlanmış\n \
+         --> <inline>:3:1\n  \
+         |\n\
+         1 | değişken sayaç = 0\n  \
+         | ------------------ ilk tanım burada\n\
+         ...\n\
+         3 | değişken sayaç = 1\n  \
+         | ^^^^^^^^^^^^^^^^^^"
+    );
+}
+
+#[test]
+fn test_duplicate_function_across_files_shows_both() {
+    let mut runtime = OtagRuntime::new();
+    runtime.add_source("yardım.otağ", "fonksiyon selam() {\n    söyle \"a\"\n}\n");
+    runtime.add_source(
+        "ana.otağ",
+        "kullan \"yardım.otağ\"\n\nfonksiyon selam() {\n    söyle \"b\"\n}\n",
+    );
+    let err = runtime.execute("ana.otağ").unwrap_err();
+    let rendered = runtime.render_error(&err, false);
+    assert!(rendered.contains(" --> ana.otağ:3:1"), "{}", rendered);
+    assert!(rendered.contains(" ::: yardım.otağ:1:1"), "{}", rendered);
+    assert!(rendered.contains("ilk tanım burada"), "{}", rendered);
+    assert!(rendered.contains("= öneri: "), "{}", rendered);
+    assert_eq!(runtime.diagnose("ana.otağ").error_count(), 1);
+}
+
+#[test]
+fn test_duplicate_definitions_are_reported_once() {
+    let sources = [
+        "fonksiyon f() {\n}\nfonksiyon f() {\n}\n",
+        "A {\n    x: tamsayı\n}\nA {\n    x: tamsayı\n}\n",
+        "eğer doğru ise\n    fonksiyon g() {\n    }\n    fonksiyon g() {\n    }\nson\n",
+    ];
+    for source in sources {
+        let diagnostics = OtagRuntime::diagnose_inline(source);
+        assert_eq!(diagnostics.error_count(), 1, "{}", source);
+        assert!(diagnostics.errors().all(|e| e.location.line > 1));
+    }
+}
+
+#[test]
+fn test_syntax_error_names_what_was_expected() {
+    let source = "x[0 = 1\n";
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(
+        err.message,
+        "'.', '[', '(' ya da bir işleç bekleniyordu, bulunan: '='"
+    );
+    let rendered = OtagRuntime::render_inline_error(source, &err, false);
+    assert_eq!(
+        rendered,
+        "sözdizimi hatası: '.', '[', '(' ya da bir işleç bekleniyordu, bulunan: '='\n \
+         --> <inline>:1:5\n  \
+         |\n\
+         1 | x[0 = 1\n  \
+         |     ^"
+    );
+
+    let err = OtagRuntime::execute_inline("söyle (1 +\n").unwrap_err();
+    assert_eq!(err.message, "Bir değer bekleniyordu, bulunan: dosya sonu");
+}
+
+#[test]
+fn test_runtime_error_underlines_the_expression() {
+    let source = "değişken sıfır = 0\nsöyle 7 % sıfır\n";
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    let rendered = OtagRuntime::render_inline_error(source, &err, false);
+    assert!(
+        rendered.ends_with("2 | söyle 7 % sıfır\n  |       ^^^^^^^^^"),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn test_rendered_runtime_error_lists_the_call_stack() {
+    let source = "fonksiyon böl(a: tamsayı) -> tamsayı {\n    return 10 / a\n}\nsöyle böl(0)\n";
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    let rendered = OtagRuntime::render_inline_error(source, &err, false);
+    assert!(
+        rendered.ends_with(
+            "2 |     return 10 / a\n  |            ^^^^^^\n  = fonksiyon böl, <inline>:4:7 konumunda çağrıldı"
+        ),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn test_every_error_is_collected_in_source_order() {
+    let source = "söyle yok\nx'ı tamsayı olarak tanımla\nx = \"on\"\nf(1)\nsöyle 1 + doğru\n";
+    let diagnostics = OtagRuntime::diagnose_inline(source);
+    let lines: Vec<usize> = diagnostics
+        .errors()
+        .map(|error| error.location.line)
+        .collect();
+    assert_eq!(lines, vec![1, 3, 4, 5]);
+    assert_eq!(diagnostics.summary(), "4 hata");
+
+    // The run itself still stops at the first error
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(err.location.line, 1);
+}
+
+#[test]
+fn test_errors_do_not_cascade() {
+    // `x` is still declared after its bad initializer, and expressions built
+    // on an error are not reported again
+    let source = "değişken x = yok + 1\nsöyle x * 2\nsöyle x.alan\neğer -yok ise\nson\n";
+    let diagnostics = OtagRuntime::diagnose_inline(source);
+    let messages: Vec<&str> = diagnostics
+        .errors()
+        .map(|error| error.message.as_str())
+        .collect();
+    assert_eq!(
+        messages,
+        vec!["Tanımlanmamış değişken: yok", "Tanımlanmamış değişken: yok"]
+    );
+}
+
+#[test]
+fn test_errors_and_warnings_are_summarised_together() {
+    let source = "sayı'ı tamsayı olarak tanımla\nsayı = \"a\"\nsöyle sayı + yok\n";
+    let diagnostics = OtagRuntime::diagnose_inline(source);
+    assert_eq!(diagnostics.warnings().count(), 1);
+    assert_eq!(diagnostics.summary(), "2 hata, 1 uyarı");
+    let rendered = OtagRuntime::render_inline_diagnostics(source, &diagnostics, false);
+    assert!(rendered.starts_with("uyarı: Ünlü uyumu"), "{}", rendered);
+    assert!(rendered.ends_with("\n\n2 hata, 1 uyarı"), "{}", rendered);
+}
+
+#[test]
+fn test_error_after_many_warnings_is_not_capped_away() {
+    let mut source = String::new();
+    for _ in 0..21 {
+        source.push_str("eğer doğru ise\n    sayı'ı tamsayı olarak tanımla\nson\n");
+    }
+    source.push_str("söyle yok\n");
+
+    let diagnostics = OtagRuntime::diagnose_inline(&source);
+    assert_eq!(diagnostics.errors().count(), 1);
+    assert_eq!(
+        diagnostics.summary(),
+        "1 hata, 21 uyarı (ilk 20 tanesi gösterildi)"
+    );
+
+    let err = OtagRuntime::execute_inline_captured(&source).unwrap_err();
+    assert_eq!(err.error_type, otag::error_reporting::ErrorType::Semantic);
+    assert_eq!(err.location.line, 64);
+}
+
+#[test]
+fn test_diagnose_reports_errors_from_every_file() {
+    let mut runtime = OtagRuntime::new();
+    runtime.add_source("yardım.otağ", "fonksiyon f() {\n    söyle yok\n}\n");
+    runtime.add_source("ana.otağ", "kullan \"yardım.otağ\"\nsöyle eksik\n");
+    let diagnostics = runtime.diagnose("ana.otağ");
+    let files: Vec<&str> = diagnostics
+        .errors()
+        .map(|error| error.location.file.as_str())
+        .collect();
+    assert_eq!(files, vec!["yardım.otağ", "ana.otağ"]);
+    let rendered = runtime.render_diagnostics(&diagnostics, false);
+    assert!(rendered.contains("2 | söyle eksik"), "{}", rendered);
+    assert!(rendered.ends_with("2 hata"), "{}", rendered);
+
+    let diagnostics = runtime.diagnose("kayıp.otağ");
+    assert_eq!(diagnostics.summary(), "1 hata");
+}
+
+#[test]
+fn test_cli_lists_every_error() {
+    let stderr = run_cli("test_tanı_çoklu.otağ", "söyle yok\nsöyle eksik\n", "asla");
+    assert!(stderr.contains("Tanımlanmamış değişken: yok"), "{}", stderr);
+    assert!(
+        stderr.contains("Tanımlanmamış değişken: eksik"),
+        "{}",
+        stderr
+    );
+    assert!(stderr.ends_with("\n\n2 hata\n"), "{}", stderr);
+}
+
+fn run_cli(file: &str, source: &str, color: &str) -> String {
+    fs::write(file, source).expect("Failed to write test file");
+    let output = Command::new(env!("CARGO_BIN_EXE_otağ-compiler"))
+        .args(["--renk", color, file])
+        .output()
+        .expect("Failed to run compiler");
+    fs::remove_file(file).ok();
+    assert!(!output.status.success());
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn test_cli_renders_snippet_without_colour() {
+    let stderr = run_cli("test_tanı_renksiz.otağ", "söyle 1 + yok\n", "asla");
+    assert_eq!(
+        stderr,
+        "hata: Tanımlanmamış değişken: yok\n \
+         --> test_tanı_renksiz.otağ:1:11\n  \
+         |\n\
+         1 | söyle 1 + yok\n  \
+         |           ^^^\n\n\
+         1 hata\n"
+    );
+}
+
+#[test]
+fn test_cli_colours_on_request() {
+    let stderr = run_cli("test_tanı_renkli.otağ", "söyle 1 + yok\n", "her-zaman");
+    assert!(stderr.starts_with("\x1b[1;31mhata\x1b[0m"), "{:?}", stderr);
+    assert!(stderr.contains("\x1b[1;31m^^^\x1b[0m"), "{:?}", stderr);
+}
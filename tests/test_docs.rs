@@ -0,0 +1,84 @@
+// Runs every Otağ example in the guides, so the docs cannot drift from the
+// language
+
+use otag::OtagRuntime;
+
+/// A ```otağ block of a Markdown file
+struct Example {
+    /// The line the code starts on
+    line: usize,
+    /// The file the guide names the block after, as in "`utils.otağ`:"
+    file: Option<String>,
+    code: String,
+}
+
+fn examples(markdown: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut current: Option<Example> = None;
+    let mut last_text = "";
+    for (number, line) in markdown.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        match current.take() {
+            None if line == "```otağ" => {
+                let file = last_text
+                    .strip_prefix('`')
+                    .and_then(|rest| rest.split_once('`'))
+                    .map(|(name, _)| name)
+                    .filter(|name| name.ends_with(".otağ"));
+                current = Some(Example {
+                    line: number + 2,
+                    file: file.map(str::to_string),
+                    code: String::new(),
+                });
+            }
+            None if !line.is_empty() => last_text = line,
+            None => {}
+            Some(example) if line == "```" => examples.push(example),
+            Some(mut example) => {
+                example.code.push_str(line);
+                example.code.push('\n');
+                current = Some(example);
+            }
+        }
+    }
+    examples
+}
+
+/// Runs each example on its own. Named blocks are kept as files, so later
+/// examples can import them.
+fn check_guide(path: &str) {
+    let markdown = std::fs::read_to_string(path).unwrap();
+    let examples = examples(&markdown);
+    assert!(!examples.is_empty(), "{} has no examples", path);
+    let mut runtime = OtagRuntime::new();
+    let failures: Vec<String> = examples
+        .into_iter()
+        .filter_map(|example| {
+            let result = match &example.file {
+                Some(file) => {
+                    runtime.add_source(file.as_str(), example.code.as_str());
+                    runtime.execute_captured(file)
+                }
+                None => OtagRuntime::execute_inline_captured(&example.code),
+            };
+            let err = result.err()?;
+            Some(format!("{}:{}: {}", path, example.line, err))
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
+
+#[test]
+fn test_guide_examples_run() {
+    check_guide("docs/klavuz.md");
+}
+
+#[test]
+fn test_introduction_examples_run() {
+    check_guide("docs/tanıtım.md");
+}
+
+#[test]
+fn test_readme_examples_run() {
+    check_guide("README.md");
+}
@@ -0,0 +1,495 @@
+// Integration tests for function calls and returns
+
+mod common;
+
+use common::{run, semantic_error};
+use otag::error_reporting::{ErrorType, RuntimeErrorKind};
+use otag::OtagRuntime;
+
+#[test]
+fn test_return_from_inside_if() {
+    let source = r#"
+fonksiyon işaret(n: tamsayı) -> metin {
+    eğer n < 0 ise
+        return "negatif"
+    yoksa
+        return "pozitif"
+    son
+}
+
+söyle işaret(-3)
+söyle işaret(4)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["negatif", "pozitif"]);
+}
+
+#[test]
+fn test_return_unwinds_through_loops() {
+    let source = r#"
+fonksiyon ilkBölen(n: tamsayı) -> tamsayı {
+    için i in 2 dan n ise
+        sayaç'ı tamsayı olarak tanımla
+        sayaç = 0
+        döngü sayaç < 1 ise
+            eğer n % i == 0 ise
+                return i
+            son
+            sayaç = sayaç + 1
+        son
+    son
+    return n
+}
+
+söyle ilkBölen(35)
+söyle ilkBölen(13)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["5", "13"]);
+}
+
+#[test]
+fn test_statements_after_return_are_skipped() {
+    let source = r#"
+fonksiyon erken() -> tamsayı {
+    eğer doğru ise
+        return 1
+    son
+    söyle "çalışmamalı"
+    return 2
+}
+
+söyle erken()
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["1"]);
+}
+
+#[test]
+fn test_bare_return_ends_at_the_line() {
+    let source = r#"
+fonksiyon yaz(n: tamsayı) {
+    değişken x = n
+    eğer x > 2 ise
+        return
+        x = 0
+    son
+    söyle x
+}
+
+yaz(1)
+yaz(5)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["1"]);
+}
+
+#[test]
+fn test_missing_return_on_some_path_is_compile_error() {
+    let source = r#"
+x'ı tamsayı olarak tanımla
+
+fonksiyon mutlak(n: tamsayı) -> tamsayı {
+    eğer n < 0 ise
+        return -n
+    son
+}
+"#;
+
+    let err = semantic_error(source);
+    assert!(err.message.contains("mutlak"));
+    assert_eq!(err.location.line, 4);
+}
+
+#[test]
+fn test_return_only_inside_loop_is_not_enough() {
+    let source = r#"
+fonksiyon bul(n: tamsayı) -> tamsayı {
+    için i in 1 dan n ise
+        return i
+    son
+}
+"#;
+
+    semantic_error(source);
+}
+
+#[test]
+fn test_function_without_return_type_needs_no_return() {
+    let source = r#"
+fonksiyon boş() {
+    eğer doğru ise
+        söyle "merhaba"
+    son
+}
+
+söyle "tamam"
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["tamam"]);
+}
+
+#[test]
+fn test_recursive_factorial() {
+    let source = r#"
+fonksiyon faktöriyel(n: tamsayı) -> tamsayı {
+    eğer n <= 1 ise
+        return 1
+    son
+    return n * faktöriyel(n - 1)
+}
+
+söyle faktöriyel(5)
+söyle faktöriyel(10)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["120", "3628800"]);
+}
+
+#[test]
+fn test_recursive_fibonacci_keeps_locals_per_call() {
+    let source = r#"
+fonksiyon fib(n: tamsayı) -> tamsayı {
+    eğer n < 2 ise
+        return n
+    son
+    önceki'ı tamsayı olarak tanımla
+    önceki = fib(n - 1)
+    return önceki + fib(n - 2)
+}
+
+söyle fib(15)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["610"]);
+}
+
+#[test]
+fn test_parameters_do_not_clobber_globals() {
+    let source = r#"
+n'ı tamsayı olarak tanımla
+n = 100
+
+fonksiyon iki_katı(n: tamsayı) -> tamsayı {
+    return n * 2
+}
+
+söyle iki_katı(4)
+söyle n
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["8", "100"]);
+}
+
+#[test]
+fn test_function_locals_are_not_visible_at_top_level() {
+    let source = r#"
+fonksiyon hesapla() -> tamsayı {
+    gizli'ı tamsayı olarak tanımla
+    gizli = 42
+    return gizli
+}
+
+söyle hesapla()
+söyle gizli
+"#;
+
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert!(err.message.contains("gizli"));
+}
+
+#[test]
+fn test_block_locals_are_dropped_after_block() {
+    let source = r#"
+x'ı tamsayı olarak tanımla
+x = 1
+
+eğer doğru ise
+    x'ı tamsayı olarak tanımla
+    x = 2
+    söyle x
+son
+
+söyle x
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["2", "1"]);
+}
+
+#[test]
+fn test_functions_see_globals_but_not_caller_locals() {
+    let source = r#"
+taban'ı tamsayı olarak tanımla
+taban = 10
+
+fonksiyon ekle(n: tamsayı) -> tamsayı {
+    return taban + n
+}
+
+fonksiyon dış() -> tamsayı {
+    taban'ı tamsayı olarak tanımla
+    taban = 1000
+    return ekle(1)
+}
+
+söyle dış()
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["11"]);
+}
+
+#[test]
+fn test_runaway_recursion_is_an_error() {
+    let source = r#"
+fonksiyon sonsuz(n: tamsayı) -> tamsayı {
+    return sonsuz(n + 1)
+}
+
+söyle sonsuz(0)
+"#;
+
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(
+        err.error_type,
+        ErrorType::Runtime(RuntimeErrorKind::CallDepthExceeded)
+    );
+    assert!(err.message.contains("sonsuz"));
+}
+
+#[test]
+fn test_deep_recursion_runs() {
+    let source = r#"
+fonksiyon toplam(n: tamsayı) -> tamsayı {
+    eğer n == 0 ise
+        return 0
+    son
+    return n + toplam(n - 1)
+}
+
+söyle toplam(3000)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["4501500"]);
+}
+
+#[test]
+fn test_call_before_definition() {
+    let source = r#"
+söyle kare(7)
+
+fonksiyon kare(n: tamsayı) -> tamsayı {
+    return n * n
+}
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["49"]);
+}
+
+#[test]
+fn test_call_before_a_global_it_reads_is_declared() {
+    let source = r#"
+söyle f()
+değişken x = 1
+fonksiyon f() -> tamsayı {
+    return x
+}
+"#;
+    let err = semantic_error(source);
+    assert_eq!(
+        err.message,
+        "'f' çağrıldığında kullandığı 'x' değişkeni henüz tanımlanmamış"
+    );
+    assert_eq!((err.location.line, err.location.column), (2, 7));
+    assert_eq!(err.labels[0].location.line, 5);
+
+    // Through another function, and for a global that is assigned
+    let source = r#"
+g()
+değişken sayaç = 0
+fonksiyon g() {
+    artır()
+}
+fonksiyon artır() {
+    sayaç = sayaç + 1
+}
+"#;
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(
+        err.message,
+        "'g' çağrıldığında kullandığı 'sayaç' değişkeni henüz tanımlanmamış"
+    );
+}
+
+#[test]
+fn test_call_after_the_globals_it_reads_are_declared() {
+    let source = r#"
+değişken x = 1
+fonksiyon f() -> tamsayı {
+    return x
+}
+x = 2
+söyle f()
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["2"]);
+}
+
+#[test]
+fn test_mutual_recursion() {
+    let source = r#"
+fonksiyon çift_mi(n: tamsayı) -> mantıksal {
+    eğer n == 0 ise
+        return doğru
+    son
+    return tek_mi(n - 1)
+}
+
+fonksiyon tek_mi(n: tamsayı) -> mantıksal {
+    eğer n == 0 ise
+        return yanlış
+    son
+    return çift_mi(n - 1)
+}
+
+söyle çift_mi(10)
+söyle tek_mi(7)
+söyle çift_mi(3)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["doğru", "doğru", "yanlış"]);
+}
+
+#[test]
+fn test_function_from_later_import_is_hoisted() {
+    let mut runtime = OtagRuntime::new();
+    runtime.add_source(
+        "yardımcı.otağ",
+        r#"
+fonksiyon üçlü(n: tamsayı) -> tamsayı {
+    return n * 3
+}
+"#,
+    );
+    runtime.add_source(
+        "main.otağ",
+        r#"
+söyle önce(2)
+
+fonksiyon önce(n: tamsayı) -> tamsayı {
+    return üçlü(n) + 1
+}
+
+kullan "yardımcı.otağ"
+"#,
+    );
+
+    let output = runtime.execute_captured("main.otağ").unwrap();
+    assert_eq!(output, vec!["7"]);
+}
+
+#[test]
+fn test_duplicate_function_across_modules_reports_both_locations() {
+    let mut runtime = OtagRuntime::new();
+    runtime.add_source(
+        "matematik.otağ",
+        r#"
+fonksiyon topla(a: tamsayı, b: tamsayı) -> tamsayı {
+    return a + b
+}
+"#,
+    );
+    runtime.add_source(
+        "main.otağ",
+        r#"
+kullan "matematik.otağ"
+
+fonksiyon topla(a: tamsayı, b: tamsayı) -> tamsayı {
+    return b + a
+}
+"#,
+    );
+
+    let err = runtime.execute("main.otağ").unwrap_err();
+    assert_eq!(err.error_type, ErrorType::Semantic);
+    assert!(err.message.contains("topla"));
+    assert!(err.message.contains("matematik.otağ:2:1"));
+    assert_eq!(err.location.file, "main.otağ");
+    assert_eq!(err.location.line, 4);
+}
+
+#[test]
+fn test_procedure_called_as_statement() {
+    let source = r#"
+fonksiyon selamla(isim: metin) {
+    söyle "Merhaba " + isim
+}
+
+selamla("Dünya")
+için i in 1 dan 2 ise
+    selamla("Otağ")
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(
+        output,
+        vec!["Merhaba Dünya", "Merhaba Otağ", "Merhaba Otağ"]
+    );
+}
+
+#[test]
+fn test_function_result_can_be_discarded() {
+    let source = r#"
+fonksiyon yazVeDöndür(n: tamsayı) -> tamsayı {
+    söyle n
+    return n
+}
+
+yazVeDöndür(3)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["3"]);
+}
+
+#[test]
+fn test_procedure_result_used_as_value_is_compile_error() {
+    let source = r#"
+fonksiyon selamla(isim: metin) {
+    söyle "Merhaba " + isim
+}
+
+söyle "başla"
+söyle selamla("Dünya")
+"#;
+
+    let err = semantic_error(source);
+    assert!(err.message.contains("selamla"));
+    assert_eq!(err.location.line, 7);
+    assert_eq!(err.location.column, 7);
+}
+
+#[test]
+fn test_undefined_function_is_compile_error() {
+    let source = r#"
+bilinmeyen(1)
+"#;
+
+    let err = semantic_error(source);
+    assert!(err.message.contains("bilinmeyen"));
+    assert_eq!(err.location.line, 2);
+}
@@ -0,0 +1,151 @@
+// Integration tests for Unicode identifiers and reserved words
+
+mod common;
+
+use common::{run, syntax_error};
+
+#[test]
+fn test_capital_turkish_letters_in_identifiers() {
+    let source = r#"
+Öğrenci {
+    İsim: metin,
+    Şube: metin,
+    Çağrı_No: tamsayı
+}
+
+ÜLKE'yi metin olarak tanımla = "Türkiye"
+Ğ'yi tamsayı olarak tanımla = 7
+değişken öğrenci = Öğrenci { İsim: "Işıl", Şube: "Ç", Çağrı_No: Ğ }
+söyle öğrenci.İsim + " " + öğrenci.Şube
+söyle öğrenci.Çağrı_No
+söyle ÜLKE
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["Işıl Ç", "7", "Türkiye"]);
+}
+
+#[test]
+fn test_functions_and_parameters_with_capitals() {
+    let source = r#"
+fonksiyon Çarp(Sayı: tamsayı, Katsayı: tamsayı) -> tamsayı {
+    return Sayı * Katsayı
+}
+
+söyle Çarp(6, 7)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["42"]);
+}
+
+#[test]
+fn test_letters_beyond_turkish_are_allowed() {
+    let source = r#"
+değişken αβ = 2
+değişken straße = "ß"
+söyle αβ
+söyle straße
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["2", "ß"]);
+}
+
+#[test]
+fn test_decomposed_and_precomposed_names_are_the_same_symbol() {
+    // Declared with a precomposed `ö`, used with `o` + combining diaeresis
+    let source = "değişken gör = 5\ngo\u{0308}r = go\u{0308}r + 1\nsöyle gör";
+
+    let output = run(source);
+    assert_eq!(output, vec!["6"]);
+}
+
+#[test]
+fn test_identifier_cannot_start_with_digit() {
+    syntax_error("değişken 1sayı = 5");
+}
+
+#[test]
+fn test_keyword_as_assignment_target_names_the_keyword() {
+    let err = syntax_error("tamsayı = 42");
+    assert_eq!(
+        err.message,
+        "'tamsayı' ayrılmış bir kelimedir, ad olarak kullanılamaz"
+    );
+    assert_eq!((err.location.line, err.location.column), (1, 1));
+    assert!(
+        err.suggestions.iter().any(|s| s.contains("örneğin: sayı")),
+        "{:?}",
+        err.suggestions
+    );
+}
+
+#[test]
+fn test_keyword_in_every_naming_position_is_reported() {
+    let cases = [
+        ("son'u tamsayı olarak tanımla", "son"),
+        ("değişken ise = 1", "ise"),
+        ("için dan in 1 dan 3 ise\nson", "dan"),
+        ("için i, in in [1] ise\nson", "in"),
+        ("fonksiyon metin() {\n}", "metin"),
+        ("fonksiyon f(adım: tamsayı) {\n}", "adım"),
+        ("dizisi {\n    x: tamsayı\n}", "dizisi"),
+        ("Nokta {\n    ve: tamsayı\n}", "ve"),
+    ];
+    for (source, keyword) in cases {
+        let err = syntax_error(source);
+        assert_eq!(
+            err.message,
+            format!(
+                "'{}' ayrılmış bir kelimedir, ad olarak kullanılamaz",
+                keyword
+            ),
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn test_reserved_name_error_points_at_the_name() {
+    let err = syntax_error("değişken x = 1\nfonksiyon f(a: tamsayı, son: tamsayı) {\n}");
+    assert_eq!((err.location.line, err.location.column), (2, 25));
+}
+
+#[test]
+fn test_keyword_used_as_a_value_is_reported() {
+    let cases = [
+        ("söyle son", "son", (1, 7)),
+        ("değişken a = 1\nsöyle a + dan", "dan", (2, 11)),
+        ("değişken a = [1]\nsöyle a[ise]", "ise", (2, 9)),
+        ("fonksiyon f(x: tamsayı) {\n}\nf(in)", "in", (3, 3)),
+    ];
+    for (source, keyword, position) in cases {
+        let err = syntax_error(source);
+        assert_eq!(
+            err.message,
+            format!(
+                "'{}' ayrılmış bir kelimedir, ad olarak kullanılamaz",
+                keyword
+            ),
+            "{}",
+            source
+        );
+        assert_eq!((err.location.line, err.location.column), position);
+    }
+}
+
+#[test]
+fn test_names_starting_with_a_keyword_are_allowed() {
+    let output = run(r#"
+değişken doğrular = 2
+değişken sonraki = doğrular + 1
+değişken veri = "x"
+değişken ince = doğru
+söyle sonraki
+söyle veri
+söyle ince
+"#);
+    assert_eq!(output, vec!["3", "x", "doğru"]);
+}
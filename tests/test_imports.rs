@@ -29,7 +29,7 @@ söyle sonuç
     fs::write("test_main.otağ", main_code).expect("Failed to write main file");
 
     let output = Command::new("cargo")
-        .args(&["run", "--", "test_main.otağ"])
+        .args(["run", "--", "test_main.otağ"])
         .output()
         .expect("Failed to run compiler");
 
@@ -89,7 +89,7 @@ söyle sonuç
     fs::write("test_nested.otağ", main_code).expect("Failed to write main file");
 
     let output = Command::new("cargo")
-        .args(&["run", "--", "test_nested.otağ"])
+        .args(["run", "--", "test_nested.otağ"])
         .output()
         .expect("Failed to run compiler");
 
@@ -136,8 +136,8 @@ söyle y
     fs::write("test_circular_a.otağ", file_a).expect("Failed to write file A");
     fs::write("test_circular_b.otağ", file_b).expect("Failed to write file B");
 
-    let output = Command::new("cargo")
-        .args(&["run", "--", "test_circular_a.otağ"])
+    let _output = Command::new("cargo")
+        .args(["run", "--", "test_circular_a.otağ"])
         .output()
         .expect("Failed to run compiler");
 
@@ -150,5 +150,4 @@ söyle y
     let _ = fs::remove_file("test_circular_b.otağ");
 
     // Test passes if we reach here without hanging
-    assert!(true);
 }
@@ -1,6 +1,7 @@
 // Integration tests using the in-memory testing API
 // This demonstrates how to test Otağ programs without creating physical files
 
+use otag::error_reporting::RuntimeErrorKind;
 use otag::{OtagRuntime, VirtualFileSystem};
 
 #[test]
@@ -291,3 +292,47 @@ söyle sonuç
         result.err()
     );
 }
+
+#[test]
+fn test_runtime_error_kinds_can_be_matched() {
+    // Embedders can tell runtime failures apart without reading the message
+    let cases = [
+        (
+            "değişken x = 0\nsöyle 5 % x",
+            RuntimeErrorKind::DivisionByZero,
+        ),
+        (
+            "değişken x = 2147483647\nsöyle x + 1",
+            RuntimeErrorKind::IntegerOverflow,
+        ),
+        (
+            "değişken x = -1\nsöyle 2 ** x",
+            RuntimeErrorKind::NegativeExponent,
+        ),
+        (
+            "değişken l = [1]\nsöyle l[1]",
+            RuntimeErrorKind::IndexOutOfBounds,
+        ),
+        (
+            "değişken a = 0\niçin i in 1 dan 3 adım a ise\nson",
+            RuntimeErrorKind::ZeroStep,
+        ),
+        ("döngü doğru ise\nson", RuntimeErrorKind::LoopLimitExceeded),
+        (
+            "fonksiyon f() -> tamsayı {\n    return f()\n}\nsöyle f()",
+            RuntimeErrorKind::CallDepthExceeded,
+        ),
+    ];
+    for (source, kind) in cases {
+        let err = OtagRuntime::execute_inline(source).unwrap_err();
+        assert_eq!(err.runtime_kind(), Some(kind), "{}: {}", source, err);
+    }
+
+    let err = OtagRuntime::execute_inline("söyle yok").unwrap_err();
+    assert_eq!(err.runtime_kind(), None);
+
+    let mut runtime = OtagRuntime::new();
+    runtime.add_source("ana.otağ", "kullan \"yok.otağ\"");
+    let err = runtime.execute("ana.otağ").unwrap_err();
+    assert_eq!(err.runtime_kind(), Some(RuntimeErrorKind::ImportFailed));
+}
@@ -0,0 +1,180 @@
+// Integration tests checking that semantic and runtime errors point at the
+// exact place in the source
+
+use otag::error_reporting::{ErrorType, OtagError, RuntimeErrorKind};
+use otag::OtagRuntime;
+
+fn error(source: &str) -> OtagError {
+    OtagRuntime::execute_inline(source).unwrap_err()
+}
+
+/// Line, column and the source text covered by the error
+fn position<'a>(err: &OtagError, source: &'a str) -> (usize, usize, &'a str) {
+    (
+        err.location.line,
+        err.location.column,
+        &source[err.location.start..err.location.end],
+    )
+}
+
+#[test]
+fn test_undefined_variable_points_at_its_use() {
+    let source = "değişken x = 1\nsöyle x + yok";
+    let err = error(source);
+    assert_eq!(err.error_type, ErrorType::Semantic);
+    assert_eq!(err.location.file, "<inline>");
+    assert_eq!(position(&err, source), (2, 11, "yok"));
+}
+
+#[test]
+fn test_type_mismatch_points_at_the_value() {
+    let source = "x'ı tamsayı olarak tanımla\nx = \"on\"";
+    let err = error(source);
+    assert_eq!(err.error_type, ErrorType::Semantic);
+    assert_eq!(position(&err, source), (2, 5, "\"on\""));
+
+    let source = "fonksiyon f(a: tamsayı) {\n}\nf(1 + 2, doğru)";
+    let err = error(source);
+    assert_eq!(position(&err, source).0, 3);
+
+    let source = "fonksiyon kare(a: tamsayı) -> tamsayı {\n    return a * 1.5\n}";
+    let err = error(source);
+    assert_eq!(position(&err, source), (2, 12, "a * 1.5"));
+}
+
+#[test]
+fn test_division_by_zero_points_at_the_division() {
+    let source = "değişken sıfır = 0\nsöyle 1 + 10 / sıfır";
+    let err = error(source);
+    assert_eq!(err.runtime_kind(), Some(RuntimeErrorKind::DivisionByZero));
+    assert_eq!(position(&err, source), (2, 11, "10 / sıfır"));
+}
+
+#[test]
+fn test_runtime_errors_inside_functions_point_at_the_body() {
+    let source = r#"
+fonksiyon böl(a: tamsayı, b: tamsayı) -> tamsayı {
+    return a % b
+}
+
+söyle böl(1, 0)
+"#;
+    let err = error(source);
+    assert_eq!(position(&err, source), (3, 12, "a % b"));
+}
+
+#[test]
+fn test_index_out_of_bounds_points_at_the_access() {
+    let source = "değişken liste = [1, 2]\nsöyle liste[5]";
+    let err = error(source);
+    assert_eq!(err.runtime_kind(), Some(RuntimeErrorKind::IndexOutOfBounds));
+    assert_eq!(position(&err, source), (2, 7, "liste[5]"));
+}
+
+#[test]
+fn test_missing_import_points_at_the_import() {
+    let mut runtime = OtagRuntime::new();
+    let source = "değişken x = 1\nkullan \"yok.otağ\"\n";
+    runtime.add_source("ana.otağ", source);
+    let err = runtime.execute("ana.otağ").unwrap_err();
+    assert_eq!(err.location.file, "ana.otağ");
+    assert_eq!(position(&err, source), (2, 1, "kullan \"yok.otağ\""));
+}
+
+#[test]
+fn test_error_in_imported_module_names_its_file_and_call_stack() {
+    let mut runtime = OtagRuntime::new();
+    let module = "fonksiyon ortalama(toplam: tamsayı, adet: tamsayı) -> tamsayı {\n    return toplam / adet\n}\n\nfonksiyon rapor(adet: tamsayı) -> tamsayı {\n    return ortalama(10, adet)\n}\n";
+    runtime.add_source("matematik.otağ", module);
+    runtime.add_source(
+        "ana.otağ",
+        "kullan \"matematik.otağ\"\n\nsöyle rapor(2)\nsöyle rapor(0)\n",
+    );
+    let err = runtime.execute("ana.otağ").unwrap_err();
+    assert_eq!(err.location.file, "matematik.otağ");
+    assert_eq!(position(&err, module), (2, 12, "toplam / adet"));
+    assert_eq!(
+        err.trace_lines(),
+        vec![
+            "fonksiyon ortalama, matematik.otağ:6:12 konumunda çağrıldı",
+            "fonksiyon rapor, ana.otağ:4:7 konumunda çağrıldı",
+        ]
+    );
+    assert!(err
+        .to_string()
+        .ends_with("\n  fonksiyon rapor, ana.otağ:4:7 konumunda çağrıldı"));
+}
+
+#[test]
+fn test_errors_outside_functions_have_no_call_stack() {
+    let err = error("değişken liste = [1]\nsöyle liste[3]");
+    assert!(err.call_stack.is_empty());
+}
+
+#[test]
+fn test_deep_recursion_trace_is_collapsed() {
+    let source = r#"
+fonksiyon sonsuz(n: tamsayı) -> tamsayı {
+    return sonsuz(n + 1)
+}
+
+söyle sonsuz(0)
+"#;
+    let err = error(source);
+    let lines = err.trace_lines();
+    // The depth reached depends on the stack the build uses per call
+    let repeats = err.call_stack.len() - 2;
+    assert_eq!(
+        lines,
+        vec![
+            format!(
+                "fonksiyon sonsuz, <inline>:3:12 konumunda çağrıldı (aynı yerden {} kez daha)",
+                repeats
+            ),
+            "fonksiyon sonsuz, <inline>:6:7 konumunda çağrıldı".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_long_traces_are_cut_short() {
+    // Two functions calling each other never repeat the same call twice in a row
+    let source = r#"
+fonksiyon tek(n: tamsayı) -> tamsayı {
+    return çift(n + 1)
+}
+
+fonksiyon çift(n: tamsayı) -> tamsayı {
+    eğer n > 30 ise
+        return n / 0
+    son
+    return tek(n + 1)
+}
+
+söyle tek(0)
+"#;
+    let err = error(source);
+    let lines = err.trace_lines();
+    assert_eq!(lines.len(), 11);
+    assert_eq!(
+        lines[10],
+        format!("... ve {} çağrı daha", err.call_stack.len() - 10)
+    );
+}
+
+#[test]
+fn test_locating_nodes_stays_linear_on_large_files() {
+    // Every node used to rescan the file from its first byte
+    let mut source: String = (0..20_000)
+        .map(|i| format!("değişken s{} = {} + {} * 2\n", i, i, i))
+        .collect();
+    source.push_str("söyle yok");
+    let started = std::time::Instant::now();
+    let err = error(&source);
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(10),
+        "{:?}",
+        started.elapsed()
+    );
+    assert_eq!(position(&err, &source), (20_001, 7, "yok"));
+}
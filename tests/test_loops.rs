@@ -0,0 +1,338 @@
+// Integration tests for `için` and `döngü` loops and `durdur`/`devam`
+
+mod common;
+
+use common::{run, semantic_error};
+use otag::OtagRuntime;
+
+#[test]
+fn test_for_loop_range_is_inclusive() {
+    let source = r#"
+için i in 1 dan 5 ise
+    söyle i
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["1", "2", "3", "4", "5"]);
+}
+
+#[test]
+fn test_for_loop_single_value_and_empty_range() {
+    let source = r#"
+için i in 3 dan 3 ise
+    söyle i
+son
+
+için i in 5 dan 1 ise
+    söyle "hiç çalışmamalı"
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["3"]);
+}
+
+#[test]
+fn test_for_loop_with_step() {
+    let source = r#"
+için i in 0 dan 10 adım 3 ise
+    söyle i
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["0", "3", "6", "9"]);
+}
+
+#[test]
+fn test_for_loop_with_negative_step() {
+    let source = r#"
+için i in 5 dan 1 adım -2 ise
+    söyle i
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["5", "3", "1"]);
+}
+
+#[test]
+fn test_for_loop_with_float_step() {
+    let source = r#"
+için x in 0 dan 1 adım 0.25 ise
+    söyle x
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["0", "0.25", "0.5", "0.75", "1"]);
+}
+
+#[test]
+fn test_for_loop_bounds_from_expressions() {
+    let source = r#"
+n'ı tamsayı olarak tanımla
+n = 3
+
+toplam'ı tamsayı olarak tanımla
+toplam = 0
+
+için i in n - 2 dan n * 2 ise
+    toplam = toplam + i
+son
+
+söyle toplam
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["21"]);
+}
+
+#[test]
+fn test_for_loop_variable_is_scoped_to_loop() {
+    let source = r#"
+i'ı tamsayı olarak tanımla
+i = 100
+
+için i in 1 dan 2 ise
+    söyle i
+son
+
+söyle i
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["1", "2", "100"]);
+}
+
+#[test]
+fn test_for_loop_zero_step_is_located_error() {
+    let source = r#"
+adım_sayısı'ı tamsayı olarak tanımla
+adım_sayısı = 0
+
+için i in 1 dan 10 adım adım_sayısı ise
+    söyle i
+son
+"#;
+
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(err.message, "Döngü adımı sıfır olamaz");
+    assert_eq!(err.location.line, 5);
+}
+
+#[test]
+fn test_for_loop_non_numeric_bounds_rejected_at_compile_time() {
+    let source = r#"
+için i in "a" dan 10 ise
+    söyle i
+son
+"#;
+
+    semantic_error(source);
+}
+
+#[test]
+fn test_break_exits_while_loop_from_nested_if() {
+    let source = r#"
+sayaç'ı tamsayı olarak tanımla
+sayaç = 0
+
+döngü sayaç < 100 ise
+    sayaç = sayaç + 1
+    eğer sayaç == 3 ise
+        durdur
+    son
+    söyle sayaç
+son
+
+söyle "bitti"
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["1", "2", "bitti"]);
+}
+
+#[test]
+fn test_continue_skips_rest_of_for_body() {
+    let source = r#"
+için i in 1 dan 6 ise
+    eğer i % 2 == 0 ise
+        devam
+    son
+    söyle i
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["1", "3", "5"]);
+}
+
+#[test]
+fn test_break_only_leaves_innermost_loop() {
+    let source = r#"
+için i in 1 dan 3 ise
+    için j in 1 dan 3 ise
+        eğer j == 2 ise
+            durdur
+        son
+        söyle i * 10 + j
+    son
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["11", "21", "31"]);
+}
+
+#[test]
+fn test_break_outside_loop_is_located_compile_error() {
+    let source = r#"
+söyle "önce"
+
+eğer doğru ise
+    durdur
+son
+"#;
+
+    let err = semantic_error(source);
+    assert!(err.message.contains("durdur"));
+    assert_eq!(err.location.line, 5);
+}
+
+#[test]
+fn test_continue_in_function_does_not_see_enclosing_loop() {
+    let source = r#"
+için i in 1 dan 2 ise
+    fonksiyon atla() -> tamsayı {
+        devam
+    }
+son
+"#;
+
+    // The missing return on line 3 is reported too, ahead of `devam`
+    let diagnostics = OtagRuntime::diagnose_inline(source);
+    assert!(diagnostics
+        .errors()
+        .any(|err| err.message.contains("devam") && err.location.line == 4));
+}
+
+#[test]
+fn test_for_each_over_array() {
+    let source = r#"
+sayılar'ı tamsayı dizisi olarak tanımla
+sayılar = [3, 5, 8]
+toplam'ı tamsayı olarak tanımla
+için sayı in sayılar ise
+    toplam = toplam + sayı
+son
+söyle toplam
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["16"]);
+}
+
+#[test]
+fn test_for_each_with_index_binding() {
+    let source = r#"
+için i, isim in ["Ali", "Ece"] ise
+    söyle i
+    söyle isim
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["0", "Ali", "1", "Ece"]);
+}
+
+#[test]
+fn test_for_each_over_string_yields_characters() {
+    let source = r#"
+için harf in "çağ" ise
+    söyle harf
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["ç", "a", "ğ"]);
+}
+
+#[test]
+fn test_for_each_break_and_continue() {
+    let source = r#"
+için sayı in [1, 2, 3, 4, 5] ise
+    eğer sayı == 2 ise
+        devam
+    son
+    eğer sayı == 4 ise
+        durdur
+    son
+    söyle sayı
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["1", "3"]);
+}
+
+#[test]
+fn test_for_each_variable_has_element_type() {
+    let source = r#"
+için sayı in [1, 2] ise
+    sayı = "metin"
+son
+"#;
+
+    let err = semantic_error(source);
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: tamsayı bekleniyordu, metin bulundu"
+    );
+}
+
+#[test]
+fn test_for_each_over_non_iterable_is_compile_error() {
+    let source = r#"
+x'ı tamsayı olarak tanımla
+için eleman in x ise
+    söyle eleman
+son
+"#;
+
+    let err = semantic_error(source);
+    assert_eq!(
+        err.message,
+        "'için' döngüsü yalnızca diziler ve metinler üzerinde çalışır, tamsayı bulundu"
+    );
+    assert_eq!(err.location.line, 3);
+}
+
+#[test]
+fn test_literal_zero_step_is_compile_error() {
+    for step in ["0", "0.0", "-0"] {
+        let source = format!("için i in 1 dan 3 adım {} ise\n    söyle i\nson", step);
+
+        let err = semantic_error(&source);
+        assert_eq!(err.message, "Döngü adımı sıfır olamaz");
+        assert_eq!((err.location.line, err.location.column), (1, 24));
+    }
+}
+
+#[test]
+fn test_zero_step_at_runtime_points_at_the_step() {
+    let source = r#"
+değişken a = 0
+için i in 1 dan 3 adım a ise
+    söyle i
+son
+"#;
+
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(
+        err.runtime_kind(),
+        Some(otag::error_reporting::RuntimeErrorKind::ZeroStep)
+    );
+    assert_eq!((err.location.line, err.location.column), (3, 24));
+}
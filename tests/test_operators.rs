@@ -0,0 +1,198 @@
+// Integration tests for arithmetic and comparison operators
+
+mod common;
+
+use common::{run, semantic_error};
+use otag::error_reporting::RuntimeErrorKind;
+use otag::OtagRuntime;
+
+#[test]
+fn test_arithmetic_precedence() {
+    let source = r#"
+a'ı tamsayı olarak tanımla
+a = 2
+
+b'ı tamsayı olarak tanımla
+b = 3
+
+c'ı tamsayı olarak tanımla
+c = 4
+
+söyle a + b * c
+söyle a * b + c
+söyle a + b * c - a / 2
+söyle 17 % 5
+söyle 2 ** 3 ** 2
+söyle 20 - 5 - 3
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["14", "10", "13", "2", "512", "12"]);
+}
+
+#[test]
+fn test_float_arithmetic() {
+    let source = r#"
+vize'ı ondalıklı olarak tanımla
+vize = 50.0
+
+final'ı ondalıklı olarak tanımla
+final = 100.0
+
+söyle vize * 0.4 + final * 0.6
+söyle 7.5 / 2.5
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["80", "3"]);
+}
+
+#[test]
+fn test_comparison_after_arithmetic() {
+    let source = r#"
+a'ı tamsayı olarak tanımla
+a = 2
+
+eğer a + 3 < 2 * 3 ise
+    söyle "küçük"
+yoksa
+    söyle "büyük"
+son
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["küçük"]);
+}
+
+#[test]
+fn test_calculator_example() {
+    let source = r#"
+fonksiyon topla(a: tamsayı, b: tamsayı) -> tamsayı {
+    return a + b
+}
+
+fonksiyon çıkar(a: tamsayı, b: tamsayı) -> tamsayı {
+    return a - b
+}
+
+x'ı tamsayı olarak tanımla
+x = 10
+
+y'ı tamsayı olarak tanımla
+y = 5
+
+söyle topla(x, y)
+söyle çıkar(x, y)
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["15", "5"]);
+}
+
+#[test]
+fn test_integer_division_by_zero_is_runtime_error() {
+    let source = r#"
+x'ı tamsayı olarak tanımla
+x = 0
+söyle 10 / x
+"#;
+
+    let err = OtagRuntime::execute_inline(source).unwrap_err();
+    assert_eq!(err.message, "Sıfıra bölme hatası");
+    assert_eq!(err.runtime_kind(), Some(RuntimeErrorKind::DivisionByZero));
+}
+
+#[test]
+fn test_equality_operators() {
+    let source = r#"
+söyle 3 == 3
+söyle 3 != 3
+söyle "elma" == "elma"
+söyle 2.5 != 1.5
+söyle [1, 2, 3] == [1, 2, 3]
+söyle [1, 2, 3] == [1, 2]
+söyle doğru == yanlış
+"#;
+
+    let output = run(source);
+    assert_eq!(
+        output,
+        vec!["doğru", "yanlış", "doğru", "doğru", "doğru", "yanlış", "yanlış"]
+    );
+}
+
+#[test]
+fn test_equality_between_different_types_is_error() {
+    semantic_error(r#"söyle 1 == "1""#);
+}
+
+#[test]
+fn test_logical_operators() {
+    let source = r#"
+yaş'ı tamsayı olarak tanımla
+yaş = 30
+
+eğer yaş >= 18 ve yaş <= 65 ise
+    söyle "çalışma çağında"
+son
+
+eğer yaş < 18 veya yaş > 65 ise
+    söyle "çalışma çağı dışında"
+son
+
+söyle değil yaş == 30
+söyle değil yanlış ve doğru
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["çalışma çağında", "yanlış", "doğru"]);
+}
+
+#[test]
+fn test_logical_operators_short_circuit() {
+    // The right operands would divide by zero if they were evaluated
+    let source = r#"
+sıfır'ı tamsayı olarak tanımla
+sıfır = 0
+
+söyle yanlış ve 1 / sıfır == 0
+söyle doğru veya 1 / sıfır == 0
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["yanlış", "doğru"]);
+}
+
+#[test]
+fn test_parentheses_and_unary_minus() {
+    let source = r#"
+a'ı tamsayı olarak tanımla
+a = 2
+
+b'ı tamsayı olarak tanımla
+b = 3
+
+c'ı tamsayı olarak tanımla
+c = 4
+
+söyle (a + b) * c
+söyle a + b * c
+söyle -a * (b - c)
+söyle -2 ** 2
+söyle (-2) ** 2
+söyle 10 - -3
+söyle -1.5 + 0.5
+söyle değil (a > b ve b > c)
+"#;
+
+    let output = run(source);
+    assert_eq!(
+        output,
+        vec!["20", "14", "2", "-4", "4", "13", "-1", "doğru"]
+    );
+}
+
+#[test]
+fn test_negating_text_is_error() {
+    semantic_error(r#"söyle -"metin""#);
+}
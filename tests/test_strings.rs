@@ -0,0 +1,74 @@
+// Integration tests for string literals: escapes, raw and multi-line strings
+
+mod common;
+
+use common::{run, syntax_error};
+
+#[test]
+fn test_escapes_are_decoded() {
+    let output = run(r#"
+söyle "Adı: \"Otağ\""
+söyle "a\tb"
+söyle "ters \\ çizgi"
+söyle "\u{15F}ehir \u{1F3D5}"
+"#);
+    assert_eq!(
+        output,
+        vec!["Adı: \"Otağ\"", "a\tb", "ters \\ çizgi", "şehir 🏕"]
+    );
+}
+
+#[test]
+fn test_hash_inside_string_is_not_a_comment() {
+    let output = run(r##"
+değişken etiket = "#1 numara"  # bu bir yorum
+söyle etiket + "#"
+"##);
+    assert_eq!(output, vec!["#1 numara#"]);
+}
+
+#[test]
+fn test_newline_escape_splits_output_lines() {
+    let output = run(r#"söyle "bir\niki""#);
+    assert_eq!(output, vec!["bir\niki"]);
+}
+
+#[test]
+fn test_raw_string_keeps_backslashes() {
+    let output = run(r#"
+değişken yol = r"C:\Kullanıcılar\otağ\n"
+söyle yol
+"#);
+    assert_eq!(output, vec![r"C:\Kullanıcılar\otağ\n"]);
+}
+
+#[test]
+fn test_multiline_string() {
+    let output = run(r#"
+fonksiyon şiir() -> metin {
+    return """
+Dağlar "yüce",
+    ovalar\tgeniş"""
+}
+söyle şiir()
+söyle r"""ham \n
+satır"""
+"#);
+    assert_eq!(
+        output,
+        vec!["Dağlar \"yüce\",\n    ovalar\tgeniş", "ham \\n\nsatır"]
+    );
+}
+
+#[test]
+fn test_bad_escape_is_located_syntax_error() {
+    let err = syntax_error("değişken a = 1\nsöyle \"yol: C:\\dosya\"");
+    assert_eq!(err.message, "Geçersiz kaçış dizisi: \\d");
+    assert_eq!((err.location.line, err.location.column), (2, 15));
+    assert!(err.suggestions.iter().any(|s| s.contains("r\"...\"")));
+}
+
+#[test]
+fn test_plain_string_cannot_span_lines() {
+    syntax_error("söyle \"bir\niki\"");
+}
@@ -0,0 +1,221 @@
+// Integration tests for struct literals, field access and field assignment
+
+mod common;
+
+use common::{run, semantic_error};
+
+const ADRES_KISI: &str = r#"
+Adres {
+    sokak: metin,
+    sehir: metin
+}
+
+Kisi {
+    isim: metin,
+    yas: tamsayı,
+    adres: Adres
+}
+"#;
+
+#[test]
+fn test_struct_literal_and_field_access() {
+    let source = format!(
+        r#"{}
+kisi'yi Kisi olarak tanımla
+kisi = Kisi {{
+    isim: "Mehmet",
+    yas: 30,
+    adres: Adres {{ sokak: "Ana Cadde", sehir: "İstanbul" }}
+}}
+söyle kisi.isim
+söyle kisi.yas + 1
+söyle kisi.adres.sehir
+"#,
+        ADRES_KISI
+    );
+    assert_eq!(run(&source), vec!["Mehmet", "31", "İstanbul"]);
+}
+
+#[test]
+fn test_fields_may_be_given_in_any_order() {
+    let source = format!(
+        r#"{}
+adres'i Adres olarak tanımla
+adres = Adres {{ sehir: "Ankara", sokak: "Atatürk Bulvarı" }}
+söyle adres
+"#,
+        ADRES_KISI
+    );
+    assert_eq!(
+        run(&source),
+        vec!["Adres { sokak: \"Atatürk Bulvarı\", sehir: \"Ankara\" }"]
+    );
+}
+
+#[test]
+fn test_declared_struct_starts_with_default_fields() {
+    let source = format!(
+        r#"{}
+kisi'yi Kisi olarak tanımla
+söyle kisi.yas
+kisi.adres.sehir = "İzmir"
+söyle kisi.adres.sehir
+"#,
+        ADRES_KISI
+    );
+    assert_eq!(run(&source), vec!["0", "İzmir"]);
+}
+
+#[test]
+fn test_field_assignment_updates_nested_field_in_place() {
+    let source = format!(
+        r#"{}
+kisi'yi Kisi olarak tanımla
+kisi = Kisi {{ isim: "Ayşe", yas: 25, adres: Adres {{ sokak: "Çarşı", sehir: "Bursa" }} }}
+kisi.yas = kisi.yas + 1
+kisi.adres.sehir = "Eskişehir"
+söyle kisi.yas
+söyle kisi.adres.sokak
+söyle kisi.adres.sehir
+"#,
+        ADRES_KISI
+    );
+    assert_eq!(run(&source), vec!["26", "Çarşı", "Eskişehir"]);
+}
+
+#[test]
+fn test_structs_are_copied_on_assignment() {
+    let source = format!(
+        r#"{}
+a'yı Adres olarak tanımla
+b'ı Adres olarak tanımla
+a.sehir = "Van"
+b = a
+b.sehir = "Muş"
+söyle a.sehir
+söyle b.sehir
+"#,
+        ADRES_KISI
+    );
+    assert_eq!(run(&source), vec!["Van", "Muş"]);
+}
+
+#[test]
+fn test_structs_as_parameters_and_return_values() {
+    let source = format!(
+        r#"{}
+fonksiyon yeni_adres(sehir: metin) -> Adres {{
+    return Adres {{ sokak: "", sehir: sehir }}
+}}
+
+fonksiyon sehri(kisi: Kisi) -> metin {{
+    return kisi.adres.sehir
+}}
+
+kisi'yi Kisi olarak tanımla
+kisi.adres = yeni_adres("Trabzon")
+söyle sehri(kisi)
+"#,
+        ADRES_KISI
+    );
+    assert_eq!(run(&source), vec!["Trabzon"]);
+}
+
+#[test]
+fn test_missing_field_in_literal_is_rejected() {
+    let source = format!(
+        r#"{}
+adres'i Adres olarak tanımla
+adres = Adres {{ sokak: "Ana Cadde" }}
+"#,
+        ADRES_KISI
+    );
+    let err = semantic_error(&source);
+    assert_eq!(
+        err.message,
+        "'Adres' yapısının 'sehir' alanına değer verilmemiş"
+    );
+}
+
+#[test]
+fn test_unknown_field_in_literal_is_rejected() {
+    let source = format!(
+        r#"{}
+adres'i Adres olarak tanımla
+adres = Adres {{ sokak: "Ana Cadde", sehir: "Rize", ulke: "Türkiye" }}
+"#,
+        ADRES_KISI
+    );
+    let err = semantic_error(&source);
+    assert!(err.message.contains("ulke"), "{}", err.message);
+}
+
+#[test]
+fn test_duplicate_field_in_literal_is_rejected() {
+    let source = format!(
+        r#"{}
+adres'i Adres olarak tanımla
+adres = Adres {{ sokak: "A", sehir: "B", sokak: "C" }}
+"#,
+        ADRES_KISI
+    );
+    let err = semantic_error(&source);
+    assert_eq!(err.message, "'sokak' alanına birden fazla değer verilmiş");
+}
+
+#[test]
+fn test_wrongly_typed_field_is_rejected() {
+    let source = format!(
+        r#"{}
+kisi'yi Kisi olarak tanımla
+kisi.yas = "otuz"
+"#,
+        ADRES_KISI
+    );
+    let err = semantic_error(&source);
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: tamsayı bekleniyordu, metin bulundu"
+    );
+}
+
+#[test]
+fn test_assignment_to_unknown_field_is_rejected() {
+    let source = format!(
+        r#"{}
+kisi'yi Kisi olarak tanımla
+kisi.adres.ulke = "Türkiye"
+"#,
+        ADRES_KISI
+    );
+    let err = semantic_error(&source);
+    assert!(err.message.contains("ulke"), "{}", err.message);
+    assert_eq!(err.location.line, 14);
+}
+
+#[test]
+fn test_field_access_on_non_struct_is_rejected() {
+    let err = semantic_error(
+        r#"
+x'ı tamsayı olarak tanımla
+söyle x.deger
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "'deger' alanına erişilemez: tamsayı bir yapı değil"
+    );
+}
+
+#[test]
+fn test_recursive_struct_is_rejected() {
+    let err = semantic_error(
+        r#"
+Dugum {
+    deger: tamsayı,
+    sonraki: Dugum
+}
+"#,
+    );
+    assert_eq!(err.message, "'Dugum' yapısı kendisini alan olarak içeremez");
+}
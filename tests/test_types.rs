@@ -0,0 +1,186 @@
+// Integration tests for the static type checker
+
+mod common;
+
+use common::{run, semantic_error};
+
+#[test]
+fn test_assignment_must_match_declared_type() {
+    let err = semantic_error(
+        r#"
+yaş'ı tamsayı olarak tanımla
+yaş = "yirmi"
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: tamsayı bekleniyordu, metin bulundu"
+    );
+    assert_eq!(err.location.line, 3);
+}
+
+#[test]
+fn test_integer_is_not_silently_a_float() {
+    let err = semantic_error(
+        r#"
+puan'ı ondalıklı olarak tanımla
+puan = 85
+"#,
+    );
+    assert!(err.suggestions.iter().any(|s| s.contains("5.0")));
+}
+
+#[test]
+fn test_condition_must_be_boolean() {
+    let err = semantic_error(
+        r#"
+x'ı tamsayı olarak tanımla
+x = 1
+
+döngü x ise
+    x = x + 1
+son
+"#,
+    );
+    assert!(err.message.contains("mantıksal"));
+    assert_eq!(err.location.line, 5);
+}
+
+#[test]
+fn test_operand_types_are_checked() {
+    for expression in [
+        "1 + 2.5",
+        "\"a\" - 1",
+        "\"a\" + 1",
+        "doğru ve 1",
+        "1 < \"b\"",
+        "1 == 1.0",
+    ] {
+        let source = format!("söyle {}", expression);
+        let err = semantic_error(&source);
+        assert_eq!(err.location.line, 1, "{}", expression);
+    }
+}
+
+#[test]
+fn test_text_concatenation_and_comparisons_type_check() {
+    let source = r#"
+ad'ı metin olarak tanımla
+ad = "Ali"
+söyle ad + " " + "Veli"
+söyle 2.5 * 2.0 >= 5.0 ve değil (ad == "Veli")
+"#;
+
+    let output = run(source);
+    assert_eq!(output, vec!["Ali Veli", "doğru"]);
+}
+
+#[test]
+fn test_undefined_variable_in_expression() {
+    let err = semantic_error(
+        r#"
+söyle 1
+söyle bilinmeyen + 1
+"#,
+    );
+    assert_eq!(err.message, "Tanımlanmamış değişken: bilinmeyen");
+    assert_eq!(err.location.line, 3);
+}
+
+#[test]
+fn test_call_arguments_checked_against_signature() {
+    let err = semantic_error(
+        r#"
+fonksiyon kare(n: tamsayı) -> tamsayı {
+    return n * n
+}
+
+söyle kare("dört")
+"#,
+    );
+    assert!(err.message.contains("'n' parametresi"));
+    assert_eq!(err.location.line, 6);
+
+    let err = semantic_error(
+        r#"
+fonksiyon kare(n: tamsayı) -> tamsayı {
+    return n * n
+}
+
+söyle kare(1, 2)
+"#,
+    );
+    assert!(err.message.contains("1 argüman"));
+}
+
+#[test]
+fn test_return_value_checked_against_signature() {
+    let err = semantic_error(
+        r#"
+fonksiyon ad() -> metin {
+    return 42
+}
+"#,
+    );
+    assert_eq!(
+        err.message,
+        "Tür uyumsuzluğu: metin bekleniyordu, tamsayı bulundu"
+    );
+    assert_eq!(err.location.line, 3);
+
+    let err = semantic_error(
+        r#"
+fonksiyon yaz(n: tamsayı) {
+    söyle n
+    return n
+}
+"#,
+    );
+    assert_eq!(err.location.line, 4);
+
+    semantic_error(
+        r#"
+fonksiyon sayı() -> tamsayı {
+    return
+}
+"#,
+    );
+}
+
+#[test]
+fn test_call_result_type_flows_into_expressions() {
+    let err = semantic_error(
+        r#"
+fonksiyon ad() -> metin {
+    return "Otağ"
+}
+
+x'ı tamsayı olarak tanımla
+x = ad() * 2
+"#,
+    );
+    assert!(err.message.contains("'*'"));
+}
+
+#[test]
+fn test_function_bodies_are_type_checked_before_running() {
+    let err = semantic_error(
+        r#"
+söyle "önce"
+
+fonksiyon hiç_çağrılmaz() -> tamsayı {
+    eğer "metin" ise
+        return 1
+    son
+    return 0
+}
+"#,
+    );
+    assert_eq!(err.location.line, 5);
+}
+
+#[test]
+fn test_array_elements_must_share_a_type() {
+    let err = semantic_error(r#"söyle [1, "iki"]"#);
+    assert!(err.message.contains("aynı türde"));
+}